@@ -0,0 +1,43 @@
+use common::{AocError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 1;
+
+    type Input = Vec<i32>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    /// Every `(` goes up a floor and every `)` goes down one.
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input
+            .trim_end()
+            .char_indices()
+            .map(|(i, ch)| match ch {
+                '(' => Ok(1),
+                ')' => Ok(-1),
+                _ => Err(AocError::at(
+                    input,
+                    i,
+                    format!("expected '(' or ')', got '{ch}'"),
+                )),
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        Ok(part2::process(input))
+    }
+}
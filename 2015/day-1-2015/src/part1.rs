@@ -0,0 +1,4 @@
+// 74
+pub fn process(offsets: &[i32]) -> i32 {
+    offsets.iter().sum()
+}
@@ -0,0 +1,11 @@
+// 1795
+pub fn process(offsets: &[i32]) -> i32 {
+    let mut offset = 0;
+    for (idx, value) in offsets.iter().enumerate() {
+        offset += value;
+        if offset <= -1 {
+            return idx as i32 + 1;
+        }
+    }
+    offset
+}
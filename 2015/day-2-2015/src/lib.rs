@@ -0,0 +1,59 @@
+use common::{error::parse_all, AocError, Solution, Vec3};
+use nom::{
+    character::complete::newline,
+    combinator::map,
+    multi::separated_list1,
+    sequence::{terminated, tuple},
+    IResult,
+};
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec3<i64>>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_all(input, cubes)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        Ok(part2::process(input))
+    }
+}
+
+fn cubes(input: &str) -> IResult<&str, Vec<Vec3<i64>>> {
+    separated_list1(newline, cube)(input)
+}
+
+/// 29x13x26
+fn cube(input: &str) -> IResult<&str, Vec3<i64>> {
+    use nom::character::complete::char;
+
+    let (input, (length, width, height)) = tuple((
+        terminated(dimension, char('x')),
+        terminated(dimension, char('x')),
+        dimension,
+    ))(input)?;
+
+    Ok((input, Vec3::new(length, width, height)))
+}
+
+fn dimension(input: &str) -> IResult<&str, i64> {
+    use nom::character::complete::u32;
+    map(u32, i64::from)(input)
+}
@@ -0,0 +1,14 @@
+use common::Vec3;
+use log::trace;
+
+/// 2*l*w + 2*w*h + 2*h*l, plus the area of the smallest side as slack.
+fn wrapping_feet(size: &Vec3<i64>) -> i64 {
+    let sides = [size.x * size.y, size.y * size.z, size.z * size.x];
+    sides.iter().map(|side| side * 2).sum::<i64>() + sides.iter().min().unwrap()
+}
+
+// 1586300
+pub fn process(presents: &[Vec3<i64>]) -> i64 {
+    trace!("{presents:?}");
+    presents.iter().map(wrapping_feet).sum()
+}
@@ -0,0 +1,20 @@
+use common::Vec3;
+use log::trace;
+
+/// The smallest perimeter of any one side to wrap it, plus its volume for the bow.
+fn ribbon_feet(size: &Vec3<i64>) -> i64 {
+    let mut sides = [size.x, size.y, size.z];
+
+    sides.sort();
+
+    let ribbon_for_present = sides.iter().take(2).map(|x| x * 2).sum::<i64>();
+    let ribbon_for_bow = sides.iter().product::<i64>();
+
+    ribbon_for_present + ribbon_for_bow
+}
+
+// 3737498
+pub fn process(presents: &[Vec3<i64>]) -> i64 {
+    trace!("{presents:?}");
+    presents.iter().map(ribbon_feet).sum()
+}
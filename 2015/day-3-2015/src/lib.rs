@@ -0,0 +1,41 @@
+use common::{AocError, Dir4, Solution};
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 3;
+
+    type Input = Vec<Dir4>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input
+            .trim_end()
+            .char_indices()
+            .map(|(i, c)| match c {
+                '^' | '>' | 'v' | '<' => Ok(Dir4::try_from(c).expect("an arrow is a direction")),
+                _ => Err(AocError::at(
+                    input,
+                    i,
+                    format!("expected a move, got '{c}'"),
+                )),
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        Ok(part2::process(input))
+    }
+}
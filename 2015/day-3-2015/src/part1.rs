@@ -0,0 +1,31 @@
+use common::{Dir4, SparseGrid, Vec2};
+use log::trace;
+
+#[derive(Debug)]
+struct Santa {
+    map: SparseGrid<()>,
+    cell: Vec2<isize>,
+}
+
+impl Santa {
+    fn do_move(&mut self, mv: Dir4) {
+        self.cell += mv.into();
+        self.map.insert(self.cell, ());
+    }
+}
+
+// 2565
+pub fn process(moves: &[Dir4]) -> u32 {
+    trace!("{moves:?}");
+
+    let mut santa = Santa {
+        map: SparseGrid::new(),
+        cell: Vec2::ZERO,
+    };
+
+    for &mv in moves {
+        santa.do_move(mv);
+    }
+
+    santa.map.len() as u32
+}
@@ -0,0 +1,47 @@
+use log::trace;
+
+use common::{Dir4, SparseGrid, Vec2};
+
+#[derive(Debug)]
+struct Santa {
+    cell: Vec2<isize>,
+}
+
+impl Santa {
+    fn new() -> Self {
+        Self { cell: Vec2::ZERO }
+    }
+    fn do_move(&mut self, mv: Dir4) {
+        self.cell += mv.into();
+    }
+}
+
+// 2639
+pub fn process(moves: &[Dir4]) -> u32 {
+    trace!("{moves:?}");
+
+    // create global map of visited cells
+    let mut map = SparseGrid::new();
+    map.insert(Vec2::ZERO, ());
+
+    // create 2 santas (real and robo)
+    let mut santas = [Santa::new(), Santa::new()];
+    let mut current_santa_idx = 0;
+
+    for &mv in moves {
+        // move current santa
+        let santa = &mut santas[current_santa_idx];
+        santa.do_move(mv);
+
+        // insert new cell
+        map.insert(santa.cell, ());
+
+        // switch to the next santa
+        current_santa_idx += 1;
+        if current_santa_idx >= santas.len() {
+            current_santa_idx = 0
+        }
+    }
+
+    map.len() as u32
+}
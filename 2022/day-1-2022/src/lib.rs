@@ -0,0 +1,63 @@
+use common::{AocError, Solution};
+use itertools::Itertools;
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    type Input = Vec<Vec<u32>>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        // note: here's an approach that uses for loops, but I'm trying to get better at using iterators
+
+        // let mut result = Vec::new();
+        // let mut current_calories = Vec::new();
+        // for line in input.lines() {
+        //     if line == "" {
+        //         result.push(current_calories);
+        //         current_calories = Vec::new();
+        //     } else {
+        //         let calories = line.parse::<u32>().expect("should be a number");
+        //         current_calories.push(calories);
+        //     }
+        // }
+
+        let elf_calories: Self::Input = input
+            .lines()
+            .group_by(|line| line.is_empty())
+            .into_iter()
+            .filter(|(is_empty, _)| !is_empty)
+            .map(|(_, group)| {
+                group
+                    .map(|line| {
+                        line.parse::<u32>().map_err(|err| {
+                            AocError::at_rest(input, line, format!("expected calories, {err}"))
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        if elf_calories.is_empty() {
+            return Err(AocError::invalid("no elves carrying calories"));
+        }
+        Ok(elf_calories)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        Ok(part2::process(input))
+    }
+}
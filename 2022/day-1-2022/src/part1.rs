@@ -0,0 +1,9 @@
+// 72602
+pub fn process(elf_calories: &[Vec<u32>]) -> u32 {
+    let most_calories = elf_calories
+        .iter()
+        .map(|x| x.iter().sum::<u32>())
+        .max()
+        .expect("parsing rejects an input without elves");
+    most_calories
+}
@@ -0,0 +1,13 @@
+use itertools::Itertools;
+
+// 207410
+pub fn process(elf_calories: &[Vec<u32>]) -> u32 {
+    let most_calories_top_3 = elf_calories
+        .iter()
+        .map(|x| x.iter().sum::<u32>())
+        .sorted()
+        .rev()
+        .take(3)
+        .sum::<u32>();
+    most_calories_top_3
+}
@@ -0,0 +1,41 @@
+use common::{AocError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+
+    /// The two columns of the strategy guide, e.g. `A Y`.
+    type Input = Vec<(char, char)>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input
+            .lines()
+            .map(|line| match line.chars().collect::<Vec<_>>()[..] {
+                [opp @ 'A'..='C', ' ', me @ 'X'..='Z'] => Ok((opp, me)),
+                _ => Err(AocError::at_rest(
+                    input,
+                    line,
+                    "expected a round like 'A Y'",
+                )),
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        Ok(part2::process(input))
+    }
+}
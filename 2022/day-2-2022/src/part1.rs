@@ -0,0 +1,65 @@
+use std::cmp::Ordering;
+
+#[derive(Debug)]
+enum Hand {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl From<char> for Hand {
+    fn from(value: char) -> Self {
+        match value {
+            'A' | 'X' => Self::Rock,
+            'B' | 'Y' => Self::Paper,
+            'C' | 'Z' => Self::Scissors,
+            _ => panic!("could not parse '{value}' into a hand"),
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    #[rustfmt::skip]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Hand::Rock, Hand::Rock) | (Hand::Paper, Hand::Paper) | (Hand::Scissors, Hand::Scissors) => Some(Ordering::Equal),
+            (Hand::Rock, Hand::Scissors) | (Hand::Paper, Hand::Rock) | (Hand::Scissors, Hand::Paper) => Some(Ordering::Greater),
+            (Hand::Rock, Hand::Paper) | (Hand::Paper, Hand::Scissors) | (Hand::Scissors, Hand::Rock) => Some(Ordering::Less),
+        }
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        matches!(
+            (self, other),
+            (Hand::Rock, Hand::Rock)
+                | (Hand::Paper, Hand::Paper)
+                | (Hand::Scissors, Hand::Scissors)
+        )
+    }
+}
+
+// 11873
+pub fn process(columns: &[(char, char)]) -> u32 {
+    let points = columns
+        .iter()
+        .map(|&(opp, me)| (Hand::from(opp), Hand::from(me)))
+        .map(|(opp, me)| {
+            let mut points = match me {
+                Hand::Rock => 1,
+                Hand::Paper => 2,
+                Hand::Scissors => 3,
+            };
+
+            if me > opp {
+                points += 6;
+            } else if me == opp {
+                points += 3;
+            }
+
+            points
+        })
+        .sum::<u32>();
+    points
+}
@@ -0,0 +1,136 @@
+use log::trace;
+use std::cmp::Ordering;
+
+enum Hand {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl std::fmt::Debug for Hand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Rock => write!(f, "🪨"),
+            Self::Paper => write!(f, "📄"),
+            Self::Scissors => write!(f, "✂️"),
+        }
+    }
+}
+
+impl Clone for Hand {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Rock => Self::Rock,
+            Self::Paper => Self::Paper,
+            Self::Scissors => Self::Scissors,
+        }
+    }
+}
+
+impl From<char> for Hand {
+    fn from(value: char) -> Self {
+        match value {
+            'A' => Self::Rock,
+            'B' => Self::Paper,
+            'C' => Self::Scissors,
+            _ => panic!("could not parse '{value}' into a hand"),
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.beats(), self.loses(), other) {
+            (beats, _, hand) if beats == *hand => Some(Ordering::Greater),
+            (_, loses, hand) if loses == *hand => Some(Ordering::Less),
+            _ => Some(Ordering::Equal),
+        }
+    }
+}
+
+impl PartialEq for Hand {
+    #[rustfmt::skip]
+    fn eq(&self, other: &Self) -> bool {
+        matches!(
+            (self, other),
+            (Hand::Rock, Hand::Rock) | (Hand::Paper, Hand::Paper) | (Hand::Scissors, Hand::Scissors)
+        )
+    }
+}
+
+impl Hand {
+    fn beats(&self) -> Self {
+        match self {
+            Self::Rock => Self::Scissors,
+            Self::Paper => Self::Rock,
+            Self::Scissors => Self::Paper,
+        }
+    }
+    fn loses(&self) -> Self {
+        match self {
+            Self::Rock => Self::Paper,
+            Self::Paper => Self::Scissors,
+            Self::Scissors => Self::Rock,
+        }
+    }
+}
+
+enum Round {
+    Win,
+    Lose,
+    Draw,
+}
+
+impl std::fmt::Debug for Round {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Win => write!(f, "⭐️"),
+            Self::Lose => write!(f, "💀"),
+            Self::Draw => write!(f, "👀"),
+        }
+    }
+}
+
+impl From<char> for Round {
+    fn from(value: char) -> Self {
+        match value {
+            'X' => Self::Lose,
+            'Y' => Self::Draw,
+            'Z' => Self::Win,
+            _ => panic!("could not parse '{value}' into a round"),
+        }
+    }
+}
+
+// 12014
+pub fn process(columns: &[(char, char)]) -> u32 {
+    let points = columns
+        .iter()
+        .map(|&(opp, round)| (Hand::from(opp), Round::from(round)))
+        .map(|(opp, round)| {
+            // round tells me wether I must win, lose, or draw – my hand depends on opponent's hand
+            let me = match round {
+                Round::Draw => opp.clone(),
+                Round::Win => opp.loses(),
+                Round::Lose => opp.beats(),
+            };
+
+            let mut points = match me {
+                Hand::Rock => 1,
+                Hand::Paper => 2,
+                Hand::Scissors => 3,
+            };
+
+            if me > opp {
+                points += 6;
+            } else if me == opp {
+                points += 3;
+            }
+
+            trace!("{me:?} vs {opp:?} = {round:?} ({points})");
+
+            points
+        })
+        .sum::<u32>();
+    points
+}
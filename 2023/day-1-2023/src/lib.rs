@@ -0,0 +1,30 @@
+use common::{AocError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        part1::process(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        part2::process(input)
+    }
+}
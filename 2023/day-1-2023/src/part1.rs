@@ -0,0 +1,20 @@
+use common::AocError;
+use log::trace;
+
+pub fn process(lines: &[String]) -> Result<i32, AocError> {
+    let mut line_numbers = vec![];
+
+    for line in lines {
+        let numbers: Vec<_> = line.chars().filter(|x| x.is_numeric()).collect();
+
+        let (Some(a), Some(b)) = (numbers.first(), numbers.last()) else {
+            return Err(AocError::invalid(format!("no digits in '{line}'")));
+        };
+
+        let number: i32 = format!("{a}{b}").parse().expect("has to be a number");
+        line_numbers.push(number);
+
+        trace!("{a}, {b} = {number}");
+    }
+    Ok(line_numbers.into_iter().sum())
+}
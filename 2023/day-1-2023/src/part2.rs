@@ -0,0 +1,56 @@
+use common::AocError;
+use log::trace;
+use std::collections::HashMap;
+
+pub fn process(lines: &[String]) -> Result<i32, AocError> {
+    let letters = vec![
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    // process lines
+    let mut total = 0;
+
+    for line in lines {
+        let mut map = HashMap::new();
+
+        // find letters
+        for (idx, letter) in letters.iter().enumerate() {
+            for (letter_idx, _) in line.match_indices(letter) {
+                map.insert(letter_idx, (idx + 1) as i32);
+            }
+        }
+
+        // find numbers
+        for (idx, char) in line.chars().enumerate() {
+            if char.is_ascii_digit() {
+                map.insert(idx, char.to_digit(10).unwrap() as i32);
+            }
+        }
+
+        // sort the keys
+        let mut sorted_keys: Vec<_> = map.keys().collect();
+        sorted_keys.sort();
+
+        // key sorted items
+        let mut sorted_numbers = vec![];
+        for key in sorted_keys {
+            if let Some(num) = &map.get(key) {
+                sorted_numbers.push(**num);
+            }
+        }
+
+        // dbg!(&sorted_numbers);
+
+        let (Some(a), Some(b)) = (sorted_numbers.first(), sorted_numbers.last()) else {
+            return Err(AocError::invalid(format!("no digits in '{line}'")));
+        };
+
+        let number: i32 = format!("{a}{b}").parse().expect("has to be a number");
+
+        trace!("{line} -> {a}, {b} = ({number})");
+
+        total += number;
+    }
+
+    Ok(total)
+}
@@ -0,0 +1,312 @@
+use common::{AocError, Solution};
+use std::collections::HashMap;
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+
+    type Input = Map;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        part2::process(input)
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    nodes: HashMap<Cell, Node>, // this could also be a 2d array
+}
+
+impl Map {
+    fn new() -> Self {
+        Self {
+            nodes: HashMap::new(),
+        }
+    }
+    fn add(&mut self, cell: Cell, node: Node) {
+        self.nodes.insert(cell, node);
+    }
+
+    fn get(&self, cell: Cell) -> Option<&Node> {
+        self.nodes.get(&cell)
+    }
+
+    /// Returns a list of cardinal neighbours, if they exist and pass conditions e.g. Pipe joins.
+    fn neighbours(&self, cell: Cell) -> Vec<&Node> {
+        let mut neighbours = vec![];
+
+        let current_node = self.get(cell).expect("should not feed wrong cell here");
+
+        let mut try_add_neighbour = |dir: Cell| {
+            let neighbour_node_op = self.get(cell + dir);
+            if let Some(neighbour_node) = neighbour_node_op {
+                if current_node.can_connect(neighbour_node) {
+                    neighbours.push(neighbour_node);
+                }
+            }
+        };
+
+        try_add_neighbour(Cell::new(1, 0));
+        try_add_neighbour(Cell::new(-1, 0));
+        try_add_neighbour(Cell::new(0, 1));
+        try_add_neighbour(Cell::new(0, -1));
+
+        neighbours
+    }
+
+    fn start(&self) -> Cell {
+        self.nodes
+            .values()
+            .find_map(|node| {
+                if node.kind == NodeKind::Start {
+                    return Some(node.cell);
+                }
+                None
+            })
+            .expect("should have an 'S' in dataset")
+    }
+
+    /// The cells of the loop through the start, in the order they are walked.
+    fn loop_from_start(&self) -> Option<Vec<Cell>> {
+        let start_cell = self.start();
+
+        // the start can have pipes joining it that aren't part of the loop, they dead end
+        for first in self.neighbours(start_cell) {
+            let mut path = vec![start_cell];
+            let (mut prev, mut current) = (start_cell, first.cell);
+            loop {
+                path.push(current);
+                let next = self
+                    .neighbours(current)
+                    .into_iter()
+                    .find(|node| node.cell != prev);
+                match next {
+                    Some(node) if node.cell == start_cell => return Some(path),
+                    Some(node) => (prev, current) = (current, node.cell),
+                    None => break,
+                }
+            }
+        }
+
+        None
+    }
+
+    fn flood_fill_from_start(&self) -> HashMap<Cell, u32> {
+        let start_cell = self.start();
+
+        let mut flood_map = HashMap::new();
+        flood_map.insert(start_cell, 0);
+
+        let mut frontier = vec![start_cell];
+
+        // keep flooding, until all neighbours are smaller
+        while let Some(current_cell) = frontier.pop() {
+            // can be used to visualize progress
+            // print_flood_map(&flood_map);
+
+            let current_flood = *flood_map
+                .get(&current_cell)
+                .expect("current node must exist");
+
+            let neighbours = self.neighbours(current_cell);
+            for neighbour in neighbours {
+                let flood_entry = flood_map.entry(neighbour.cell);
+                use std::collections::hash_map::Entry;
+                match flood_entry {
+                    Entry::Occupied(mut value) => {
+                        if current_flood < *value.get_mut() {
+                            *value.get_mut() = current_flood + 1;
+                            frontier.push(neighbour.cell);
+                        }
+                    }
+                    Entry::Vacant(_) => {
+                        flood_map.insert(neighbour.cell, current_flood + 1);
+                        frontier.push(neighbour.cell);
+                    }
+                }
+            }
+        }
+
+        flood_map
+    }
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+struct Cell {
+    x: i32,
+    y: i32,
+}
+
+impl Cell {
+    fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+}
+
+impl std::ops::Add for Cell {
+    type Output = Cell;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl std::ops::Sub for Cell {
+    type Output = Cell;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+#[derive(Hash, PartialEq, Eq, Debug)]
+struct Node {
+    cell: Cell,
+    kind: NodeKind,
+}
+
+impl Node {
+    fn from_symbol(cell: Cell, symbol: char) -> Option<Node> {
+        Some(Self {
+            cell,
+            kind: symbol.try_into().ok()?,
+        })
+    }
+
+    fn can_connect(&self, other_node: &Node) -> bool {
+        self.cell_in_connections(other_node.cell) && other_node.cell_in_connections(self.cell)
+    }
+
+    fn cell_in_connections(&self, cell: Cell) -> bool {
+        self.connections().contains(&cell)
+    }
+
+    fn connections(&self) -> Vec<Cell> {
+        match &self.kind {
+            NodeKind::Start => {
+                vec![
+                    self.cell + Cell::new(1, 0),
+                    self.cell + Cell::new(-1, 0),
+                    self.cell + Cell::new(0, 1),
+                    self.cell + Cell::new(0, -1),
+                ]
+            }
+            NodeKind::Ground => {
+                vec![]
+            }
+            NodeKind::Pipe(pipe) => pipe
+                .local_connections()
+                .into_iter()
+                .map(|local_cell| local_cell + self.cell)
+                .collect::<Vec<_>>(),
+        }
+    }
+}
+
+#[derive(Hash, PartialEq, Eq, Debug)]
+enum NodeKind {
+    Start,
+    Ground,
+    Pipe(Pipe),
+}
+
+impl TryFrom<char> for NodeKind {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            'S' => Self::Start,
+            '.' => Self::Ground,
+            '|' => Self::Pipe(Pipe::Vertical),
+            '-' => Self::Pipe(Pipe::Horizontal),
+            'L' => Self::Pipe(Pipe::NorthEast),
+            'J' => Self::Pipe(Pipe::NorthWest),
+            '7' => Self::Pipe(Pipe::SouthWest),
+            'F' => Self::Pipe(Pipe::SouthEast),
+            _ => return Err(()),
+        })
+    }
+}
+
+#[derive(Hash, PartialEq, Eq, Debug)]
+enum Pipe {
+    Vertical,   // |
+    Horizontal, // -
+    NorthEast,  // L
+    NorthWest,  // J
+    SouthWest,  // 7
+    SouthEast,  // F
+}
+
+impl Pipe {
+    fn local_connections(&self) -> Vec<Cell> {
+        let mut connections = vec![];
+        match self {
+            Pipe::Vertical => {
+                connections.push(Cell::new(0, 1));
+                connections.push(Cell::new(0, -1));
+            }
+            Pipe::Horizontal => {
+                connections.push(Cell::new(1, 0));
+                connections.push(Cell::new(-1, 0));
+            }
+            Pipe::NorthEast => {
+                connections.push(Cell::new(0, -1));
+                connections.push(Cell::new(1, 0));
+            }
+            Pipe::NorthWest => {
+                connections.push(Cell::new(0, -1));
+                connections.push(Cell::new(-1, 0));
+            }
+            Pipe::SouthWest => {
+                connections.push(Cell::new(0, 1));
+                connections.push(Cell::new(-1, 0));
+            }
+            Pipe::SouthEast => {
+                connections.push(Cell::new(0, 1));
+                connections.push(Cell::new(1, 0));
+            }
+        }
+        connections
+    }
+}
+
+fn parse(input: &str) -> Result<Map, AocError> {
+    let mut map = Map::new();
+
+    for (y, line) in input.lines().enumerate() {
+        for (x, (i, symbol)) in line.char_indices().enumerate() {
+            let cell = Cell::new(x as i32, y as i32);
+            let node = Node::from_symbol(cell, symbol)
+                .ok_or_else(|| AocError::at_rest(input, &line[i..], "expected a pipe"))?;
+            map.add(cell, node);
+        }
+    }
+
+    if !map.nodes.values().any(|node| node.kind == NodeKind::Start) {
+        return Err(AocError::invalid("no 'S' to start from"));
+    }
+    Ok(map)
+}
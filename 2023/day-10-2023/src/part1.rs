@@ -0,0 +1,42 @@
+use log::{log_enabled, trace, Level};
+use std::collections::HashMap;
+
+use crate::{Cell, Map};
+
+// 7107
+pub fn process(map: &Map) -> u32 {
+    trace!("{map:?}");
+
+    let flood_fill_map = map.flood_fill_from_start();
+
+    if log_enabled!(Level::Trace) {
+        print_flood_map(&flood_fill_map);
+    }
+
+    *flood_fill_map.values().max().unwrap()
+}
+
+fn print_flood_map(map: &HashMap<Cell, u32>) {
+    let rows = map.iter().map(|item| item.0.x).max().unwrap() as usize;
+    let cols = map.iter().map(|item| item.0.y).max().unwrap() as usize;
+
+    let mut visual_map = vec![vec![0; cols]; rows];
+
+    for (y, visual_row) in visual_map.iter_mut().enumerate() {
+        for (x, visual_cell) in visual_row.iter_mut().enumerate() {
+            if let Some(item) = map.get(&Cell::new(x as i32, y as i32)) {
+                *visual_cell = ((*item as f32) / 10.0) as u32;
+            }
+        }
+    }
+
+    // print visual map
+    let mut visual = String::new();
+    for row in &visual_map {
+        for cell in row {
+            visual += &format!("{:3} ", cell);
+        }
+        visual.push('\n');
+    }
+    trace!("flood fill distances / 10\n{visual}");
+}
@@ -0,0 +1,99 @@
+use common::{polygon::Polygon, AocError, Vec2};
+use log::{debug, log_enabled, trace, Level};
+use std::collections::HashSet;
+
+use crate::{Cell, Map, NodeKind, Pipe};
+
+impl NodeKind {
+    fn symbol(&self, bold: bool) -> char {
+        match self {
+            NodeKind::Start => '!', // ●
+            NodeKind::Ground => '░',
+            NodeKind::Pipe(pipe) => pipe.symbol(bold),
+        }
+    }
+}
+
+impl Pipe {
+    fn symbol(&self, bold: bool) -> char {
+        if bold {
+            match self {
+                Pipe::Vertical => '┃',
+                Pipe::Horizontal => '━',
+                Pipe::NorthEast => '┗',
+                Pipe::NorthWest => '┛',
+                Pipe::SouthWest => '┓',
+                Pipe::SouthEast => '┏',
+            }
+        } else {
+            match self {
+                Pipe::Vertical => '│',
+                Pipe::Horizontal => '─',
+                Pipe::NorthEast => '└',
+                Pipe::NorthWest => '┘',
+                Pipe::SouthWest => '┐',
+                Pipe::SouthEast => '┌',
+            }
+        }
+    }
+}
+
+// 281
+pub fn process(map: &Map) -> Result<u32, AocError> {
+    let pipe_loop = map
+        .loop_from_start()
+        .ok_or_else(|| AocError::invalid("no loop through the 'S'"))?;
+
+    // every cell of the loop is a corner, the tiles enclosed are the lattice points inside
+    let vertices = pipe_loop
+        .iter()
+        .map(|cell| Vec2::new(cell.x as i64, cell.y as i64))
+        .collect();
+    let polygon = Polygon::new(vertices);
+
+    if log_enabled!(Level::Trace) {
+        print_inside_nodes(map, &pipe_loop, &polygon);
+    }
+
+    Ok(polygon.interior_points() as u32)
+}
+
+fn print_inside_nodes(map: &Map, pipe_loop: &[Cell], polygon: &Polygon) {
+    let rows = map.nodes.iter().filter(|item| item.0.x == 0).count();
+    let cols = map.nodes.iter().filter(|item| item.0.y == 0).count();
+
+    debug!("map size: (rows: {}, columns: {})", rows, cols);
+
+    let pipe_loop: HashSet<_> = pipe_loop.iter().collect();
+    let mut visual = String::new();
+
+    // ░░░░░░░░░░░
+    // ░!━━━━━━━┓░
+    // ░┃┏━━━━━┓┃░
+    // ░┃┃░░░░░┃┃░
+    // ░┃┃░░░░░┃┃░
+    // ░┃┗━┓░┏━┛┃░
+    // ░┃╳╳┃░┃╳╳┃░
+    // ░┗━━┛░┗━━┛░
+    // ░░░░░░░░░░░
+    for y in 0..rows {
+        for x in 0..cols {
+            let cell = Cell::new(x as i32, y as i32);
+
+            if let Some(node) = map.get(cell) {
+                let on_loop = pipe_loop.contains(&cell);
+                // print inside nodes as "X"
+                visual.push(
+                    if !on_loop && polygon.contains(Vec2::new(x as i64, y as i64)) {
+                        '╳'
+                    } else {
+                        node.kind.symbol(on_loop)
+                    },
+                );
+            }
+        }
+
+        visual.push('\n');
+    }
+    trace!("inside nodes\n{visual}");
+}
@@ -0,0 +1,81 @@
+use common::{solution::Params, AocError, Matrix, Solution, Vec2};
+use log::debug;
+use std::fmt::Display;
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+
+    type Input = Matrix<Thing>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        Self::part2_with(input, &Params::new())
+    }
+
+    /// The examples use smaller expansion factors than the real puzzle.
+    fn part2_with(input: &Self::Input, params: &Params) -> Result<Self::Output2, AocError> {
+        Ok(part2::process(
+            input,
+            params.get("expansion").unwrap_or(1_000_000),
+        ))
+    }
+}
+
+type Cell = Vec2<isize>;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq)]
+pub enum Thing {
+    Space,
+    Galaxy,
+}
+
+impl Display for Thing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            Self::Space => '.',
+            Self::Galaxy => '#',
+        };
+        write!(f, "{symbol}")
+    }
+}
+
+impl Thing {
+    fn is_space(&self) -> bool {
+        *self == Thing::Space
+    }
+}
+
+/// Galaxies as `x` the column and `y` the row.
+fn galaxies(map: &Matrix<Thing>) -> Vec<Cell> {
+    map.positions_of(&Thing::Galaxy)
+        .map(|pos| pos.to_isize().expect("a matrix fits in isize"))
+        .collect()
+}
+
+fn parse(input: &str) -> Result<Matrix<Thing>, AocError> {
+    let map = Matrix::try_parse_grid(input, |char| match char {
+        '.' => Ok(Thing::Space),
+        '#' => Ok(Thing::Galaxy),
+        _ => Err("expected '.' or '#'"),
+    })?;
+    debug!("map size: {}x{}", map.ncols(), map.nrows());
+    Ok(map)
+}
@@ -0,0 +1,125 @@
+use common::Matrix;
+use log::{debug, log_enabled, trace, Level};
+
+use crate::{galaxies, Cell, Thing};
+
+// 9214785
+pub fn process(map: &Matrix<Thing>) -> usize {
+    debug!("expanding space");
+    let map = expand_space(map);
+
+    let galaxies = galaxies(&map);
+
+    debug!("galaxies ({})", galaxies.len());
+    galaxies.iter().for_each(|p| trace!("galaxy: {p}"));
+
+    // The outer loop iterates over each element in the list, and the inner loop iterates over the remaining elements in the list starting from the next element after the current one. This ensures that you don't get duplicate pairs, and the order of elements in the pair doesn't matter.
+    let galaxies_paths = galaxies
+        .iter()
+        .enumerate()
+        .map(|(index, &a)| {
+            let sum = galaxies
+                .iter()
+                .skip(index + 1)
+                .map(|&b| line(a, b).len())
+                .sum::<usize>();
+
+            trace!("path sum: {a} -> {sum:?}");
+
+            sum
+        })
+        .sum::<usize>();
+
+    galaxies_paths
+}
+
+/// Any rows or columns that contain no galaxies should all actually be twice as big.
+fn expand_space(map: &Matrix<Thing>) -> Matrix<Thing> {
+    let empty_rows: Vec<_> = (map.rows().enumerate())
+        .filter(|(_, row)| row.iter().all(Thing::is_space))
+        .map(|(y, _)| y)
+        .collect();
+    let empty_cols: Vec<_> = (map.cols().enumerate())
+        .filter(|(_, col)| col.iter().all(Thing::is_space))
+        .map(|(x, _)| x)
+        .collect();
+
+    // back to front, so the rows and columns still to double don't move
+    let mut map = map.clone();
+    for &y in empty_rows.iter().rev() {
+        map.insert_row(y, vec![Thing::Space; map.ncols()]);
+    }
+    for &x in empty_cols.iter().rev() {
+        map.insert_col(x, vec![Thing::Space; map.nrows()]);
+    }
+
+    if log_enabled!(Level::Trace) {
+        trace!("expanded\n{map}");
+    }
+
+    map
+}
+
+fn line(from: Cell, to: Cell) -> Vec<Cell> {
+    let mut points = Vec::new();
+    let mut plot_callback = |x, y| {
+        points.push(Cell::new(x, y));
+    };
+
+    plot_line(from.x, from.y, to.x, to.y, &mut plot_callback);
+
+    // --- line print ---
+    // println!("line: from: {} to: {}, len: {}", from, to, points.len());
+    // for p in &points {
+    //     print!("{p}|");
+    // }
+    // println!();
+    // --- end ---
+
+    points
+}
+
+// Note: Line algorithm is unnecessary for this challenge it's just fun to learn about it. To solve the challenge all you need to do is get the sum delta of x and y e.g. (6-4=2)+(2-1=1)=3.
+// https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm
+// https://stackoverflow.com/questions/8936183/bresenham-lines-w-o-diagonal-movement
+fn plot_line<F>(x0: isize, y0: isize, x1: isize, y1: isize, mut plot: F)
+where
+    F: FnMut(isize, isize),
+{
+    let dx = (x1 - x0).abs();
+    let dy = -(y1 - y0).abs();
+    let sx = if x0 < x1 { 1 } else { -1 };
+    let sy = if y0 < y1 { 1 } else { -1 };
+    let mut err = dx + dy;
+
+    let mut x = x0;
+    let mut y = y0;
+
+    // Should you wish to keep the starting point.
+    // plot(x0, y0);
+
+    while x != x1 || y != y1 {
+        if 2 * err - dy > dx - 2 * err {
+            // horizontal step
+            err += dy;
+            x += sx;
+        } else {
+            // vertical step
+            err += dx;
+            y += sy;
+        }
+
+        plot(x, y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::line;
+    use crate::Cell;
+    #[test]
+    fn day11_2023_lines() {
+        let points = line(Cell::new(0, 0), Cell::new(-5, 5));
+        assert_eq!(points.len(), 10);
+    }
+}
@@ -0,0 +1,76 @@
+use common::Matrix;
+use log::{debug, trace};
+
+use crate::{galaxies, Cell, Thing};
+
+// 613_686_987_427
+pub fn process(map: &Matrix<Thing>, galaxy_expansion: usize) -> usize {
+    let galaxies = galaxies_in_expand_space(map, galaxy_expansion);
+
+    debug!("galaxies ({})", galaxies.len());
+    galaxies.iter().for_each(|p| trace!("galaxy: {p}"));
+
+    // The outer loop iterates over each element in the list, and the inner loop iterates over the remaining elements in the list starting from the next element after the current one. This ensures that you don't get duplicate pairs, and the order of elements in the pair doesn't matter.
+    let galaxies_paths = galaxies
+        .iter()
+        .enumerate()
+        .map(|(index, a)| {
+            let sum = galaxies
+                .iter()
+                .skip(index + 1)
+                .map(|b| a.manhattan(*b))
+                .sum::<usize>();
+
+            trace!("path sum: {a} -> {sum:?}");
+
+            sum
+        })
+        .sum::<usize>();
+
+    galaxies_paths
+}
+
+/// Any rows or columns that contain no galaxies should all actually be a million times bigger.
+fn galaxies_in_expand_space(map: &Matrix<Thing>, galaxy_expansion: usize) -> Vec<Cell> {
+    let empty_rows: Vec<_> = (map.rows().enumerate())
+        .filter(|(_, row)| row.iter().all(Thing::is_space))
+        .map(|(y, _)| y as isize)
+        .collect();
+    let empty_cols: Vec<_> = (map.cols().enumerate())
+        .filter(|(_, col)| col.iter().all(Thing::is_space))
+        .map(|(x, _)| x as isize)
+        .collect();
+
+    // 10 times needs to turn into x9, while 1 needs to stay 1
+    let step = usize::max(galaxy_expansion - 1, 1) as isize;
+
+    galaxies(map)
+        .into_iter()
+        .map(|galaxy| {
+            let ex = empty_cols.iter().filter(|&&x| x < galaxy.x).count() as isize;
+            let ey = empty_rows.iter().filter(|&&y| y < galaxy.y).count() as isize;
+            galaxy + Cell::new(ex, ey) * step
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day11;
+    use common::Solution;
+
+    #[test]
+    fn day11_2023_expansion() {
+        // the middle row and column are empty
+        let map = Day11::parse("#..\n...\n..#\n").unwrap();
+
+        let galaxies = galaxies_in_expand_space(&map, 10);
+        assert_eq!(galaxies, [Cell::new(0, 0), Cell::new(11, 11)]);
+        assert_eq!(process(&map, 10), 22);
+
+        // twice as big adds one row and column
+        assert_eq!(galaxies_in_expand_space(&map, 2)[1], Cell::new(3, 3));
+        assert_eq!(process(&map, 2), 6);
+    }
+}
@@ -0,0 +1,25 @@
+use log::{debug, trace};
+use std::collections::HashMap;
+
+use crate::{count_arrangements, SpringProblem};
+
+/* ----- */
+/* Setup */
+/* ----- */
+
+// 7402
+pub fn process(problems: &[SpringProblem]) -> usize {
+    debug!("{} problems", problems.len());
+    problems
+        .iter()
+        .map(|sp| {
+            let result = count_arrangements(&sp.states, &sp.groups, &mut HashMap::new(), 0);
+            trace!("{result}\t{sp:?}");
+            result
+        })
+        .sum()
+}
+
+/* ------- */
+/* Testing */
+/* ------- */
@@ -0,0 +1,40 @@
+use log::{debug, trace};
+use std::collections::HashMap;
+
+use crate::{count_arrangements, SpringProblem};
+
+/* ----- */
+/* Setup */
+/* ----- */
+
+// 3_384_337_640_277
+pub fn process(problems: &[SpringProblem]) -> usize {
+    debug!("{} problems", problems.len());
+    problems
+        .iter()
+        .map(|sp| {
+            let count = 5;
+
+            let states = std::iter::repeat_n(sp.states.as_str(), count)
+                .collect::<Vec<_>>()
+                .join("?");
+
+            let groups = sp
+                .groups
+                .iter()
+                .cycle()
+                .take(sp.groups.len() * count)
+                .copied()
+                .collect::<Vec<usize>>();
+
+            let result = count_arrangements(&states, &groups, &mut HashMap::new(), 0);
+            trace!("{result}\t{states}, {groups:?}");
+
+            result
+        })
+        .sum()
+}
+
+/* ------- */
+/* Testing */
+/* ------- */
@@ -0,0 +1,149 @@
+use common::{error::parse_all, AocError, BitGrid, Solution};
+use std::fmt::Display;
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+
+    /// Mirrors set, ground clear.
+    type Input = Vec<BitGrid>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let matrices = parse_all(input, parsing::parse_matrices)?;
+        Ok(matrices
+            .iter()
+            .map(|mat| BitGrid::from_matrix(mat, |state| *state == State::Mirror))
+            .collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        part1::process(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        part2::process(input)
+    }
+}
+
+/* ----- */
+/* Types */
+/* ----- */
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum State {
+    Mirror,
+    Ground,
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            Self::Mirror => '#',
+            Self::Ground => '.',
+        };
+        write!(f, "{symbol}")
+    }
+}
+
+/* ----- */
+/* Logic */
+/* ----- */
+
+/// Summarizes a pattern: the columns left of a vertical line of reflection, or 100 times the
+/// rows above a horizontal one. `smudges` is how many cells must differ across the line.
+fn summarize(grid: &BitGrid, smudges: usize) -> Option<usize> {
+    // transposing turns columns into rows, so one search finds both kinds of lines
+    reflection(&grid.transposed(), smudges).or_else(|| reflection(grid, smudges).map(|i| i * 100))
+}
+
+/// The number of rows above the horizontal line of reflection, if there is one.
+fn reflection(grid: &BitGrid, smudges: usize) -> Option<usize> {
+    (1..grid.nrows()).find(|&split| differences(grid, split) == smudges)
+}
+
+/// How many cells differ from their mirror image across the line above row `split`, each pair
+/// of rows is an XOR and a popcount.
+fn differences(grid: &BitGrid, split: usize) -> usize {
+    let above = (0..split).rev();
+    let below = split..grid.nrows();
+    above.zip(below).map(|(a, b)| grid.row_distance(a, b)).sum()
+}
+
+/* ------- */
+/* Parsing */
+/* ------- */
+
+mod parsing {
+    use super::State;
+    use common::Matrix;
+    use nom::{
+        branch::alt,
+        character::complete::newline,
+        combinator::{map, opt, value, verify},
+        multi::{many0, many1},
+        sequence::terminated,
+        IResult,
+    };
+
+    pub fn parse_matrices(input: &str) -> IResult<&str, Vec<Matrix<State>>> {
+        many0(parse_matrix)(input)
+    }
+
+    fn parse_matrix(input: &str) -> IResult<&str, Matrix<State>> {
+        let rows = verify(many1(parse_row), |rows: &Vec<Vec<State>>| {
+            rows.iter().all(|row| row.len() == rows[0].len())
+        });
+        terminated(
+            map(rows, |rows| {
+                Matrix::from_iterator(rows.len(), rows[0].len(), rows.into_iter().flatten())
+            }),
+            opt(newline),
+        )(input)
+    }
+
+    fn parse_row(input: &str) -> IResult<&str, Vec<State>> {
+        terminated(states, opt(newline))(input)
+    }
+
+    fn states(input: &str) -> IResult<&str, Vec<State>> {
+        many1(state)(input)
+    }
+
+    fn state(input: &str) -> IResult<&str, State> {
+        use nom::character::complete::char;
+        alt((
+            value(State::Mirror, char('#')),
+            value(State::Ground, char('.')),
+        ))(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day13_2023_reflection() {
+        let grids = Day13::parse("#.\n#.\n\n#.\n##\n").unwrap();
+
+        // the rows match, the columns differ in both rows
+        assert_eq!(differences(&grids[0], 1), 0);
+        assert_eq!(differences(&grids[0].transposed(), 1), 2);
+        assert_eq!(summarize(&grids[0], 0), Some(100));
+
+        // one smudge either way, the vertical line wins
+        assert_eq!(summarize(&grids[1], 0), None);
+        assert_eq!(summarize(&grids[1], 1), Some(1));
+        assert!(Day13::part1(&grids).is_err());
+    }
+}
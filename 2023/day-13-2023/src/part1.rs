@@ -0,0 +1,15 @@
+use common::{AocError, BitGrid};
+
+use crate::summarize;
+
+// Every pattern has a vertical or a horizontal line of reflection, both sides match exactly.
+
+// 35691
+pub fn process(grids: &[BitGrid]) -> Result<usize, AocError> {
+    (grids.iter().enumerate())
+        .map(|(i, grid)| {
+            summarize(grid, 0)
+                .ok_or_else(|| AocError::invalid(format!("pattern {} has no reflection", i + 1)))
+        })
+        .sum()
+}
@@ -0,0 +1,20 @@
+use common::{AocError, BitGrid};
+
+use crate::summarize;
+
+// Each pattern has exactly one smudge, fixing it gives a new line of reflection. Rather than
+// trying every fix, look for the line where exactly one cell differs from its mirror image.
+
+// 39037
+pub fn process(grids: &[BitGrid]) -> Result<usize, AocError> {
+    (grids.iter().enumerate())
+        .map(|(i, grid)| {
+            summarize(grid, 1).ok_or_else(|| {
+                AocError::invalid(format!(
+                    "pattern {} has no reflection one smudge away",
+                    i + 1
+                ))
+            })
+        })
+        .sum()
+}
@@ -0,0 +1,64 @@
+use common::{matrix::Matrix, AocError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+
+    type Input = Matrix<char>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        Ok(part2::process(input))
+    }
+}
+
+/// Rolls every round rock `O` as far north as it goes, cube rocks `#` stay put.
+fn tilt_north(matrix: &mut Matrix<char>) {
+    for col in 0..matrix.ncols() {
+        // the northernmost spot a rock in this column can still roll to
+        let mut free = 0;
+        for row in 0..matrix.nrows() {
+            match matrix[(row, col)] {
+                '#' => free = row + 1,
+                'O' => {
+                    matrix[(row, col)] = '.';
+                    matrix[(free, col)] = 'O';
+                    free += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Each round rock weighs as many rows as it is from the south edge.
+fn north_load(matrix: &Matrix<char>) -> usize {
+    matrix
+        .positions_of(&'O')
+        .map(|pos| matrix.nrows() - pos.y)
+        .sum()
+}
+
+fn parse(input: &str) -> Result<Matrix<char>, AocError> {
+    Matrix::try_parse_grid(input, |char| match char {
+        'O' | '#' | '.' => Ok(char),
+        _ => Err("expected 'O', '#' or '.'"),
+    })
+}
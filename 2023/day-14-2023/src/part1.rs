@@ -0,0 +1,15 @@
+use common::matrix::Matrix;
+use log::trace;
+
+use crate::{north_load, tilt_north};
+
+// 105_461
+pub fn process(matrix: &Matrix<char>) -> usize {
+    let mut matrix = matrix.clone();
+
+    trace!("\n{matrix}");
+    tilt_north(&mut matrix);
+    trace!("\n{matrix}");
+
+    north_load(&matrix)
+}
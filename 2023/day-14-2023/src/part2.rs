@@ -0,0 +1,27 @@
+use common::{cycle, matrix::Matrix};
+use log::trace;
+
+use crate::{north_load, tilt_north};
+
+// 102_829
+pub fn process(matrix: &Matrix<char>) -> usize {
+    trace!("\n{matrix}");
+
+    // the rocks settle into a loop long before a billion spin cycles, so skip the repeats
+    let matrix = cycle::state_at(matrix.clone(), spin, 1_000_000_000);
+
+    trace!("\n{matrix}");
+
+    north_load(&matrix)
+}
+
+/// One spin cycle: tilts north, west, south, then east.
+fn spin(matrix: &Matrix<char>) -> Matrix<char> {
+    let mut matrix = matrix.clone();
+    // turning clockwise brings the next side up north
+    for _ in 0..4 {
+        tilt_north(&mut matrix);
+        matrix.rotate_cw();
+    }
+    matrix
+}
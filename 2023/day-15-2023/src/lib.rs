@@ -0,0 +1,41 @@
+use common::{AocError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 15;
+
+    type Input = Vec<String>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.trim().split(',').map(str::to_owned).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        Ok(part2::process(input))
+    }
+}
+
+/// The HASH algorithm: turns any string into a number in the range 0 to 255.
+fn hash(input: &str) -> u8 {
+    let mut result: usize = 0;
+    for item in input.chars() {
+        result += item as usize;
+        result *= 17;
+        result %= 256;
+    }
+    result as u8
+}
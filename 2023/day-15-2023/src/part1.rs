@@ -0,0 +1,15 @@
+use crate::hash;
+
+// 501_680
+pub fn process(steps: &[String]) -> usize {
+    steps.iter().map(|step| hash(step) as usize).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hash;
+    #[test]
+    fn day15_2023_hash() {
+        assert_eq!(hash("rn=1"), 30);
+    }
+}
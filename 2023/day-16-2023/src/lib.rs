@@ -0,0 +1,212 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use common::AocError;
+use common::Dir4;
+use common::Matrix;
+use common::Solution;
+use common::Vec2;
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 16;
+
+    type Input = Matrix<Cell>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        Ok(part2::process(input))
+    }
+}
+
+#[derive(Clone)]
+pub struct Cell {
+    symbol: Symbol,
+    energy: HashSet<Dir4>,
+}
+
+impl Cell {
+    fn new(symbol: Symbol) -> Cell {
+        Self {
+            symbol,
+            energy: HashSet::new(),
+        }
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol)
+    }
+}
+
+#[derive(Clone)]
+enum Symbol {
+    Empty,
+    ForwardSlash,
+    BackSlash,
+    Vertical,
+    Horizontal,
+}
+
+impl Display for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", char::from(self))
+    }
+}
+
+impl TryFrom<char> for Symbol {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Symbol::Empty),
+            '/' => Ok(Symbol::ForwardSlash),
+            '\\' => Ok(Symbol::BackSlash),
+            '|' => Ok(Symbol::Vertical),
+            '-' => Ok(Symbol::Horizontal),
+            _ => Err(format!(
+                "expected a mirror, a splitter or '.', got '{value}'"
+            )),
+        }
+    }
+}
+
+impl From<&Symbol> for char {
+    fn from(value: &Symbol) -> Self {
+        match value {
+            Symbol::Empty => '.',
+            Symbol::ForwardSlash => '/',
+            Symbol::BackSlash => '\\',
+            Symbol::Vertical => '|',
+            Symbol::Horizontal => '-',
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct Beam {
+    pos: Vec2<isize>,
+    dir: Dir4,
+}
+
+impl Beam {
+    fn new(pos: Vec2<isize>, dir: Dir4, mat: &mut Matrix<Cell>) -> Option<Self> {
+        if let Some(element) = mat.get_mut_element_signed(pos.y, pos.x) {
+            element.energy.insert(dir);
+            return Some(Self { pos, dir });
+        }
+        None
+    }
+
+    fn step(&mut self, mat: &Matrix<Cell>) -> BeamStep {
+        if let Some(cell) = mat.get_element_signed(self.pos.y, self.pos.x) {
+            let beam_step = self.step_on_symbol(&cell.symbol);
+            if beam_step == BeamStep::Moved || beam_step == BeamStep::Reflected {
+                self.advance_forward();
+            }
+            return beam_step;
+        }
+        // walked out of bounds
+        BeamStep::Faded
+    }
+
+    fn advance_forward(&mut self) {
+        self.pos += self.dir.offset();
+    }
+
+    fn step_on_symbol(&mut self, symbol: &Symbol) -> BeamStep {
+        match symbol {
+            Symbol::Empty => BeamStep::Moved,
+            Symbol::ForwardSlash => {
+                // ---> /  and  / <---  turn left, ^ /  and  v /  turn right
+                self.dir = if self.dir.is_vertical() {
+                    self.dir.turn_right()
+                } else {
+                    self.dir.turn_left()
+                };
+                BeamStep::Reflected
+            }
+            Symbol::BackSlash => {
+                // ---> \  and  \ <---  turn right, ^ \  and  v \  turn left
+                self.dir = if self.dir.is_vertical() {
+                    self.dir.turn_left()
+                } else {
+                    self.dir.turn_right()
+                };
+                BeamStep::Reflected
+            }
+            Symbol::Vertical if self.dir.is_vertical() => BeamStep::Moved,
+            Symbol::Horizontal if !self.dir.is_vertical() => BeamStep::Moved,
+            Symbol::Vertical | Symbol::Horizontal => BeamStep::Split(self.split()),
+        }
+    }
+
+    fn split(&mut self) -> Vec<Beam> {
+        let left_beam = Beam {
+            dir: self.dir.turn_left(),
+            ..self.clone()
+        };
+        let right_beam = Beam {
+            dir: self.dir.turn_right(),
+            ..self.clone()
+        };
+        vec![left_beam, right_beam]
+    }
+}
+
+#[derive(PartialEq)]
+enum BeamStep {
+    Moved,
+    Reflected,
+    Split(Vec<Beam>),
+    Faded,
+}
+
+fn step(mut beam: Beam, beams: &mut Vec<Beam>, matrix: &mut Matrix<Cell>) {
+    match beam.step(matrix) {
+        BeamStep::Moved | BeamStep::Reflected => {
+            keep_alive_when_first_energy_pass(beam, beams, matrix)
+        }
+        BeamStep::Split(split_beams) => {
+            for split_beam in split_beams {
+                keep_alive_when_first_energy_pass(split_beam, beams, matrix);
+            }
+        }
+        BeamStep::Faded => { /* faded – nothing to do */ }
+    }
+}
+
+fn keep_alive_when_first_energy_pass(beam: Beam, beams: &mut Vec<Beam>, matrix: &mut Matrix<Cell>) {
+    if let Some(cell) = matrix.get_mut_element_signed(beam.pos.y, beam.pos.x) {
+        if !cell.energy.contains(&beam.dir) {
+            cell.energy.insert(beam.dir);
+            beams.push(beam);
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Matrix<Cell>, AocError> {
+    let matrix = Matrix::try_parse_grid(input, |char| Symbol::try_from(char).map(Cell::new))?;
+    if matrix.nrows() == 0 {
+        return Err(AocError::invalid("no contraption to shine a beam into"));
+    }
+    Ok(matrix)
+}
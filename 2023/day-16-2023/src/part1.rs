@@ -0,0 +1,48 @@
+use common::render::Highlight;
+use common::{Dir4, Matrix, Vec2};
+use log::{log_enabled, trace, Level};
+
+use crate::{step, Beam, Cell};
+
+// 7482
+pub fn process(matrix: &Matrix<Cell>) -> usize {
+    let mut matrix = matrix.clone();
+    trace!("\n{matrix}");
+
+    // all active beams
+    let mut beams = vec![];
+
+    // add first beam
+    if let Some(beam) = Beam::new(Vec2::ZERO, Dir4::Right, &mut matrix) {
+        step(beam, &mut beams, &mut matrix);
+    }
+
+    // steps all active beams until they all fade –  this can happen when beams go out of bounds or beam's current cell is already energized in the same direction
+    while !beams.is_empty() {
+        let mut next_beams: Vec<Beam> = vec![];
+        for beam in beams {
+            step(beam, &mut next_beams, &mut matrix);
+        }
+        beams = next_beams;
+    }
+
+    // print energy matrices
+    if log_enabled!(Level::Trace) {
+        let energized = (matrix.indexed_iter())
+            .filter(|(_, cell)| !cell.energy.is_empty())
+            .map(|(pos, _)| pos);
+        let energy = matrix
+            .render()
+            .compact()
+            .highlight(energized, Highlight::Char('#'));
+        trace!("energy\n{energy}");
+        let energy_len = matrix.render_with(|cell| cell.energy.len().to_string());
+        trace!("energy per cell\n{energy_len}");
+    }
+
+    // count cells with at least one energy
+    matrix
+        .iter()
+        .filter_map(|cell| (!cell.energy.is_empty()).then_some(1))
+        .sum::<usize>()
+}
@@ -0,0 +1,83 @@
+use common::render::Highlight;
+use common::{Dir4, Matrix, Vec2};
+use log::{log_enabled, trace, Level};
+
+use crate::{step, Beam, Cell};
+
+// 7896
+pub fn process(matrix: &Matrix<Cell>) -> usize {
+    trace!("\n{matrix}");
+
+    // shoot beams from every edge and return the highest number of energized cells from any one beam
+    let mut energies = vec![];
+
+    // left edge – right dir
+    let dir = Dir4::Right;
+    for row in 0..matrix.nrows() {
+        let start_pos = Vec2::new(0, row as isize);
+        energies.push(get_total_energy(matrix.clone(), start_pos, dir));
+    }
+
+    // right edge – left dir
+    let dir = Dir4::Left;
+    for row in 0..matrix.nrows() {
+        let start_pos = Vec2::new(matrix.nrows() as isize, row as isize);
+        energies.push(get_total_energy(matrix.clone(), start_pos, dir));
+    }
+
+    // top edge – down dir
+    let dir = Dir4::Down;
+    for col in 0..matrix.ncols() {
+        let start_pos = Vec2::new(col as isize, 0);
+        energies.push(get_total_energy(matrix.clone(), start_pos, dir));
+    }
+
+    // bottom edge – down dir
+    let dir = Dir4::Up;
+    for col in 0..matrix.ncols() {
+        let start_pos = Vec2::new(col as isize, matrix.nrows() as isize);
+        energies.push(get_total_energy(matrix.clone(), start_pos, dir));
+    }
+
+    energies
+        .into_iter()
+        .max()
+        .expect("parsing rejects an empty contraption")
+}
+
+fn get_total_energy(mut matrix: Matrix<Cell>, pos: Vec2<isize>, dir: Dir4) -> usize {
+    // all active beams
+    let mut beams = vec![];
+
+    // add first beam
+    if let Some(beam) = Beam::new(pos, dir, &mut matrix) {
+        step(beam, &mut beams, &mut matrix);
+    }
+
+    // steps all active beams until they all fade –  this can happen when beams go out of bounds or beam's current cell is already energized in the same direction
+    while !beams.is_empty() {
+        let mut next_beams: Vec<Beam> = vec![];
+        for beam in beams {
+            step(beam, &mut next_beams, &mut matrix);
+        }
+        beams = next_beams;
+    }
+
+    // print energy matrices
+    if log_enabled!(Level::Trace) {
+        let energized = (matrix.indexed_iter())
+            .filter(|(_, cell)| !cell.energy.is_empty())
+            .map(|(pos, _)| pos);
+        let energy = matrix
+            .render()
+            .compact()
+            .highlight(energized, Highlight::Char('#'));
+        trace!("energy\n{energy}");
+    }
+
+    // count cells with at least one energy
+    matrix
+        .iter()
+        .filter_map(|cell| (!cell.energy.is_empty()).then_some(1))
+        .sum::<usize>()
+}
@@ -0,0 +1,177 @@
+use common::{
+    render::{Colour, Highlight},
+    AocError, Dir4, Matrix, Solution, Vec2,
+};
+use log::{log_enabled, trace, Level};
+use std::collections::{BinaryHeap, HashMap};
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 17;
+
+    type Input = Matrix<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        part1::process(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        part2::process(input)
+    }
+}
+
+#[derive(PartialEq, Eq, Clone)]
+struct Node {
+    cell: Vec2<usize>,
+    dir: Dir4,
+    steps: usize,
+    cost: usize,
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // We only care about the cost of the node when comparing them,
+        // because it's only used for the binary heap. Reverse compare for min-heap.
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl Node {
+    fn new(cell: Vec2<usize>, dir: Dir4, steps: usize, cost: usize) -> Self {
+        Self {
+            cell,
+            dir,
+            steps,
+            cost,
+        }
+    }
+
+    // Extracts just the state that's necessary to track as we traverse the graph.
+    // Arguably, state should be encapsulated in the node.
+    fn state(&self) -> State {
+        State {
+            cell: self.cell,
+            dir: self.dir,
+            steps: self.steps,
+        }
+    }
+}
+
+#[derive(Hash, PartialEq, Eq, Clone)]
+struct State {
+    cell: Vec2<usize>,
+    dir: Dir4,
+    steps: usize,
+}
+
+// Dijkstra algorithm.
+// Note: we only care about the cost of getting there, the path is just tracked for tracing.
+// Each part has its own crucible rules, so it decides which neighbours can be reached from a node.
+fn total_cost_of_shortest_path(
+    start: Vec2<usize>,
+    end: Vec2<usize>,
+    matrix: &Matrix<usize>,
+    get_neighbours: fn(&Node, &Matrix<usize>) -> Vec<Node>,
+) -> Result<usize, AocError> {
+    // Min costs track node costs, only cell, dir, steps -> ((2,3), (1,0), 2) => 7
+    let mut costs: HashMap<State, usize> = HashMap::new();
+    let mut frontier: BinaryHeap<Node> = BinaryHeap::new();
+    // The state each state was last reached from, only filled in when tracing.
+    let tracing = log_enabled!(Level::Trace);
+    let mut came_from: HashMap<State, State> = HashMap::new();
+
+    // Set costs to zero in all starting directions,
+    // Start the frontier with the two initial directions.
+    let node_east = Node::new(start, Dir4::Right, 0, 0);
+    costs.insert(node_east.state(), 0);
+    frontier.push(node_east);
+
+    let node_south = Node::new(start, Dir4::Down, 0, 0);
+    costs.insert(node_south.state(), 0);
+    frontier.push(node_south);
+
+    // Pop the smallest cost node from the frontier (it's a min-heap)
+    while let Some(current) = frontier.pop() {
+        if current.cell == end {
+            if tracing {
+                trace_path(matrix, &came_from, current.state());
+            }
+            return Ok(current.cost);
+        }
+
+        for neighbour in get_neighbours(&current, matrix) {
+            let new_cost = current.cost + neighbour.cost;
+
+            // Compare this new total cost to our current min cost (if we have one),
+            // If new cost is more than what we already have – don't bother with it.
+            if let Some(existing_cost) = costs.get(&neighbour.state()) {
+                if new_cost >= *existing_cost {
+                    continue;
+                }
+            }
+
+            // This neighbour was either never visited or its cost is smaller,
+            // track the cost and add the node to the frontier.
+            let mut new_node = neighbour;
+            new_node.cost = new_cost;
+            costs.insert(new_node.state(), new_node.cost);
+            if tracing {
+                came_from.insert(new_node.state(), current.state());
+            }
+            frontier.push(new_node);
+        }
+    }
+
+    Err(AocError::invalid(
+        "no path to the bottom right that the crucible can take",
+    ))
+}
+
+fn trace_path(matrix: &Matrix<usize>, came_from: &HashMap<State, State>, end: State) {
+    let mut path = vec![end.cell];
+    let mut state = &end;
+    while let Some(previous) = came_from.get(state) {
+        path.push(previous.cell);
+        state = previous;
+    }
+    path.reverse();
+
+    let drawn = matrix
+        .render()
+        .compact()
+        .highlight(path.iter().copied(), Highlight::Colour(Colour::Yellow))
+        .path(&path);
+    trace!("shortest path\n{drawn}");
+}
+
+fn parse(input: &str) -> Result<Matrix<usize>, AocError> {
+    let matrix = Matrix::try_parse_grid(input, |char| {
+        char.to_digit(10)
+            .map(|loss| loss as usize)
+            .ok_or("expected a digit")
+    })?;
+    if matrix.nrows() == 0 {
+        return Err(AocError::invalid("no city blocks to cross"));
+    }
+    Ok(matrix)
+}
@@ -0,0 +1,45 @@
+use common::{AocError, Dir4, Matrix, Vec2};
+use log::trace;
+
+use crate::{total_cost_of_shortest_path, Node};
+
+// 1260
+pub fn process(matrix: &Matrix<usize>) -> Result<usize, AocError> {
+    trace!("\n{matrix}");
+
+    let start = Vec2::new(0, 0);
+    let end = Vec2::new(matrix.ncols() - 1, matrix.nrows() - 1);
+
+    total_cost_of_shortest_path(start, end, matrix, get_neighbours)
+}
+
+fn get_neighbours(node: &Node, matrix: &Matrix<usize>) -> Vec<Node> {
+    // Move a minimum of four blocks in the same direction before it can turn.
+    if node.steps < 4 {
+        return match matrix.neighbour(node.cell, node.dir.into()) {
+            Some((cell, cost)) => vec![Node::new(cell, node.dir, node.steps + 1, *cost)],
+            None => vec![],
+        };
+    }
+
+    let mut neighbours: Vec<Node> = vec![];
+    for dir in Dir4::ALL {
+        // No backtracking – only forward, left, right.
+        if node.dir.opposite() == dir {
+            continue;
+        }
+
+        // Try to get the node in that direction, could be out of bounds.
+        if let Some((cell, cost)) = matrix.neighbour(node.cell, dir.into()) {
+            // Generate new neighbours for the next iteration of the frontier.
+            if node.dir != dir {
+                // Reset direction steps when we change direction.
+                neighbours.push(Node::new(cell, dir, 1, *cost));
+            } else if node.steps < 10 {
+                // We're walking to the same direction, make sure the steps are less than ten.
+                neighbours.push(Node::new(cell, dir, node.steps + 1, *cost));
+            }
+        }
+    }
+    neighbours
+}
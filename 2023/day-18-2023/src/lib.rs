@@ -0,0 +1,108 @@
+use common::{error::parse_all, polygon::Polygon, AocError, Dir4, Solution, Vec2};
+use log::{debug, trace};
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 18;
+
+    type Input = Vec<Step>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_all(input, parsing::parse)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        part2::process(input)
+    }
+}
+
+// https://www.youtube.com/watch?v=bGWK76_e-LM
+
+/// A line of the dig plan, the hex color hides the real instruction for part 2.
+#[derive(Debug)]
+pub struct Step {
+    instruction: Instruction,
+    color: String,
+}
+
+#[derive(Debug, Clone)]
+struct Instruction {
+    dir: Dir4,
+    steps: usize,
+}
+
+/// Total size of the lagoon: the interior plus the trench that was dug around it.
+fn lagoon_size(instructions: Vec<Instruction>) -> usize {
+    let moves = instructions
+        .into_iter()
+        .map(|ins| (ins.dir, ins.steps as i64));
+    let lagoon = Polygon::from_moves(Vec2::new(0, 0), moves);
+
+    trace!("{:?}", lagoon.vertices());
+    debug!("boundary: {}", lagoon.boundary_points());
+    debug!("area: {}", lagoon.signed_area().abs());
+
+    // the trench is a cube wide, so the cubes dug are the lattice points inside the polygon
+    // through their centres and on its boundary
+    (lagoon.interior_points() + lagoon.boundary_points()) as usize
+}
+
+mod parsing {
+
+    use super::{Instruction, Step};
+    use common::Dir4;
+
+    use nom::{
+        character::complete::{alphanumeric1, newline, one_of, space1},
+        combinator::{map, map_res},
+        multi::separated_list1,
+        sequence::{delimited, preceded, terminated, tuple},
+        IResult,
+    };
+
+    pub(crate) fn parse(input: &str) -> IResult<&str, Vec<Step>> {
+        separated_list1(newline, parse_step)(input)
+    }
+
+    fn parse_step(input: &str) -> IResult<&str, Step> {
+        use nom::character::complete::u64;
+        map(
+            tuple((
+                terminated(parse_direction, space1),
+                terminated(map(u64, |n| n as usize), space1),
+                parse_color,
+            )),
+            |(dir, steps, color)| Step {
+                instruction: Instruction { dir, steps },
+                color,
+            },
+        )(input)
+    }
+
+    fn parse_direction(input: &str) -> IResult<&str, Dir4> {
+        map_res(one_of("RULD"), Dir4::try_from)(input)
+    }
+
+    fn parse_color(input: &str) -> IResult<&str, String> {
+        use nom::character::complete::char;
+        delimited(
+            char('('),
+            preceded(char('#'), map(alphanumeric1, |a: &str| a.to_owned())),
+            char(')'),
+        )(input)
+    }
+}
@@ -0,0 +1,7 @@
+use crate::{lagoon_size, Step};
+
+// 48_400
+pub fn process(plan: &[Step]) -> usize {
+    let instructions = plan.iter().map(|step| step.instruction.clone()).collect();
+    lagoon_size(instructions)
+}
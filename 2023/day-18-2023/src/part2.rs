@@ -0,0 +1,31 @@
+use common::{AocError, Dir4};
+
+use crate::{lagoon_size, Instruction, Step};
+
+impl Instruction {
+    fn from_color(color: &str) -> Result<Self, AocError> {
+        let invalid = || AocError::invalid(format!("'#{color}' isn't a hidden instruction"));
+
+        let dir = match color.chars().last() {
+            Some('0') => Dir4::Right,
+            Some('1') => Dir4::Down,
+            Some('2') => Dir4::Left,
+            Some('3') => Dir4::Up,
+            _ => return Err(invalid()),
+        };
+
+        let step_hex = color.chars().take(5).collect::<String>();
+        let steps = usize::from_str_radix(step_hex.as_str(), 16).map_err(|_| invalid())?;
+
+        Ok(Self { dir, steps })
+    }
+}
+
+// 72_811_019_847_283
+pub fn process(plan: &[Step]) -> Result<usize, AocError> {
+    let instructions = plan
+        .iter()
+        .map(|step| Instruction::from_color(&step.color))
+        .collect::<Result<_, _>>()?;
+    Ok(lagoon_size(instructions))
+}
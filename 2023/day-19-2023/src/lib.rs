@@ -0,0 +1,290 @@
+use common::{error::parse_all, AocError, Solution};
+use std::{collections::HashMap, fmt::Display};
+
+pub mod part1;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 19;
+
+    type Input = Context;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let ctx = parse_all(input, parsing::parse)?;
+
+        // every part starts at "in", and every jump must land somewhere
+        let mut names = vec!["in"];
+        for workflow in ctx.workflows.values() {
+            let actions = workflow.expressions.iter().map(|ex| &ex.action);
+            for action in actions.chain([&workflow.default]) {
+                if let Action::GoTo(name) = action {
+                    names.push(name);
+                }
+            }
+        }
+        if let Some(name) = names
+            .iter()
+            .find(|name| !ctx.workflows.contains_key(**name))
+        {
+            return Err(AocError::invalid(format!("no workflow called '{name}'")));
+        }
+        Ok(ctx)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Self::Output2, AocError> {
+        todo!()
+    }
+}
+
+// Idea: instead of making so many structs we could just use tuples:
+// e.g. type Something = (String, HashSet<String, (String, char, char, usize, String)>).
+
+type Workflows = HashMap<String, Workflow>;
+type Parts = Vec<Part>;
+type Expressions = Vec<Expression>;
+
+#[derive(Debug)]
+pub struct Context {
+    workflows: Workflows,
+    parts: Parts,
+}
+
+#[derive(Debug)]
+struct Workflow {
+    name: String,             // qkq
+    expressions: Expressions, // a < 2006 : qkq
+    default: Action,          // Reject
+}
+
+impl Display for Workflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> ", self.name)?;
+        for ex in &self.expressions {
+            write!(f, "{}", Into::<char>::into(&ex.rating))?;
+            write!(f, " {} ", Into::<char>::into(&ex.operator))?;
+            write!(f, "{:?}", ex.value)?;
+            write!(f, "={:?}", ex.action)?;
+            write!(f, ",")?;
+        }
+        write!(f, ":{:?}", self.default)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Expression {
+    rating: Rating,     // x
+    operator: Operator, // <
+    value: usize,       // 1000
+    action: Action,     // GoTo -> xyz
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord)]
+enum Rating {
+    ExtremelyCoolLooking, // x
+    Musical,              // m
+    Aerodynamic,          // a
+    Shiny,                // s
+}
+
+impl TryFrom<char> for Rating {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'x' => Ok(Rating::ExtremelyCoolLooking),
+            'm' => Ok(Rating::Musical),
+            'a' => Ok(Rating::Aerodynamic),
+            's' => Ok(Rating::Shiny),
+            _ => Err(format!("could not parse rating from {value}")),
+        }
+    }
+}
+
+impl From<&Rating> for char {
+    fn from(value: &Rating) -> Self {
+        match value {
+            Rating::ExtremelyCoolLooking => 'x',
+            Rating::Musical => 'm',
+            Rating::Aerodynamic => 'a',
+            Rating::Shiny => 's',
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Operator {
+    LessThan,
+    GreaterThan,
+}
+
+impl From<&Operator> for char {
+    fn from(value: &Operator) -> Self {
+        match value {
+            Operator::LessThan => '<',
+            Operator::GreaterThan => '>',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Action {
+    Accept,
+    Reject,
+    GoTo(String),
+}
+
+#[derive(Debug)]
+struct Part {
+    ratings: Vec<(Rating, usize)>,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Part {{ x: {}, m: {}, a: {}, s: {} }}",
+            &self.ratings[0].1, &self.ratings[1].1, &self.ratings[2].1, &self.ratings[3].1
+        )
+    }
+}
+
+impl Part {
+    fn total(&self) -> usize {
+        self.ratings.iter().map(|x| x.1).sum::<usize>()
+    }
+}
+
+// Parsing is half the challenge.
+mod parsing {
+    use std::collections::HashMap;
+
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{alpha1, anychar, digit1, newline},
+        combinator::map,
+        multi::{many1, separated_list1},
+        sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+        IResult,
+    };
+
+    use super::{Action, Context, Expression, Operator, Part, Parts, Rating, Workflow, Workflows};
+
+    pub(crate) fn parse(input: &str) -> IResult<&str, Context> {
+        map(
+            separated_pair(parse_workflows, tag("\n\n"), parse_parts),
+            |(workflows, ratings)| Context {
+                workflows,
+                parts: ratings,
+            },
+        )(input)
+    }
+
+    fn parse_workflows(input: &str) -> IResult<&str, Workflows> {
+        let (input, workflows) = separated_list1(newline, parse_workflow)(input)?;
+
+        let mut map = HashMap::new();
+        for workflow in workflows {
+            map.insert(workflow.name.to_owned(), workflow);
+        }
+
+        Ok((input, map))
+    }
+
+    fn parse_workflow(input: &str) -> IResult<&str, Workflow> {
+        use nom::character::complete::char;
+        let (input, (name, (expressions, default))) = tuple((
+            alpha1,
+            delimited(
+                char('{'),
+                pair(many1(terminated(parse_expression, char(','))), parse_action),
+                char('}'),
+            ),
+        ))(input)?;
+
+        Ok((
+            input,
+            Workflow {
+                name: name.to_owned(),
+                expressions,
+                default,
+            },
+        ))
+    }
+
+    // a>1716:R
+    fn parse_expression(input: &str) -> IResult<&str, Expression> {
+        let (input, rating) = anychar(input)?;
+        let rating = rating.try_into().map_err(|_| {
+            nom::Err::Error(nom::error::make_error(input, nom::error::ErrorKind::Char))
+        })?;
+        let (input, operator) = anychar(input)?;
+        let operator = match operator {
+            '<' => Operator::LessThan,
+            '>' => Operator::GreaterThan,
+            _ => {
+                return Err(nom::Err::Error(nom::error::make_error(
+                    input,
+                    nom::error::ErrorKind::Char,
+                )))
+            }
+        };
+        let (input, value) = map(digit1, |v: &str| v.parse::<usize>().unwrap())(input)?;
+        let (input, action) = preceded(tag(":"), parse_action)(input)?;
+        Ok((
+            input,
+            Expression {
+                rating,
+                operator,
+                value,
+                action,
+            },
+        ))
+    }
+
+    fn parse_action(input: &str) -> IResult<&str, Action> {
+        let (input, action) = alpha1(input)?;
+        let action = match action {
+            "A" => Action::Accept,
+            "R" => Action::Reject,
+            _ => Action::GoTo(action.to_owned()),
+        };
+        Ok((input, action))
+    }
+
+    fn parse_parts(input: &str) -> IResult<&str, Parts> {
+        use nom::character::complete::char;
+        separated_list1(newline, delimited(char('{'), parse_part_values, char('}')))(input)
+    }
+
+    fn parse_part_values(input: &str) -> IResult<&str, Part> {
+        use nom::character::complete::char;
+        let (input, ratings) = separated_list1(char(','), parse_part_value)(input)?;
+        Ok((input, Part { ratings }))
+    }
+
+    fn parse_part_value(input: &str) -> IResult<&str, (Rating, usize)> {
+        use nom::character::complete::char;
+        let (input, (rating, value)) = separated_pair(
+            anychar,
+            char('='),
+            map(digit1, |v: &str| v.parse::<usize>().unwrap()),
+        )(input)?;
+
+        let rating = rating.try_into().map_err(|_| {
+            nom::Err::Failure(nom::error::make_error(input, nom::error::ErrorKind::Char))
+        })?;
+
+        Ok((input, (rating, value)))
+    }
+}
@@ -0,0 +1,68 @@
+use log::trace;
+
+use crate::{Action, Context, Expression, Operator, Part, Workflow, Workflows};
+
+// 332_145
+
+pub fn process(ctx: &Context) -> usize {
+    for x in ctx.workflows.values() {
+        trace!("{x:?}");
+    }
+
+    ctx.parts
+        .iter()
+        .filter_map(|part| {
+            if check_part_acceptance(part, &ctx.workflows) {
+                return Some(part.total());
+            }
+            None
+        })
+        .sum::<usize>()
+}
+
+fn check_part_acceptance(part: &Part, workflows: &Workflows) -> bool {
+    let mut current_workflow = workflows.get("in").expect("should have 'in' workflow");
+    loop {
+        match run_part_through_workflow(current_workflow, part) {
+            Action::Accept => return true,
+            Action::Reject => return false,
+            Action::GoTo(next_workflow_name) => {
+                let next_workflow = workflows
+                    .get(&next_workflow_name)
+                    .expect("next workflow should be valid");
+                current_workflow = next_workflow;
+            }
+        };
+    }
+}
+
+fn run_part_through_workflow(workflow: &Workflow, part: &Part) -> Action {
+    // For every part's rating run it through the matching expressions,
+    // return the first successful action.
+    for ex in &workflow.expressions {
+        let value = part
+            .ratings
+            .iter()
+            .find(|x| x.0 == ex.rating)
+            .expect("part should have all xmas values")
+            .1;
+
+        // Return the first successful expression.
+        if let Some(action) = evaluate(ex, value) {
+            return action;
+        }
+    }
+    // When all else fails just return the default action.
+    workflow.default.clone()
+}
+
+fn evaluate(expr: &Expression, value: usize) -> Option<Action> {
+    if match expr.operator {
+        Operator::LessThan => value < expr.value,
+        Operator::GreaterThan => value > expr.value,
+    } {
+        Some(expr.action.clone())
+    } else {
+        None
+    }
+}
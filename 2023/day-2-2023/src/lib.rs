@@ -0,0 +1,98 @@
+use common::{AocError, Solution};
+use std::collections::HashMap;
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    // In this approach we will deserialize all string data into concrete types, then we will calculate the sum.
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        // deserialize
+        let mut games = vec![];
+        for line in input.lines() {
+            let Some((game_id, sets)) = line.split_once(':') else {
+                return Err(AocError::at_rest(
+                    input,
+                    line,
+                    "expected 'Game <id>: <sets>'",
+                ));
+            };
+
+            let game_id = game_id
+                .strip_prefix("Game ")
+                .and_then(|id| id.parse().ok())
+                .ok_or_else(|| AocError::at_rest(input, game_id, "expected 'Game <id>'"))?;
+
+            let mut game = Game::new(game_id);
+
+            for set in sets.split(';') {
+                let mut map = HashMap::new();
+
+                for cube in set.split(',').map(str::trim) {
+                    let Some((cube_count, cube_color)) = cube.split_once(' ') else {
+                        return Err(AocError::at_rest(input, cube, "expected '<count> <color>'"));
+                    };
+                    let cube_count: i32 = cube_count.parse().map_err(|_| {
+                        AocError::at_rest(input, cube_count, "expected a number of cubes")
+                    })?;
+                    let color = match cube_color {
+                        "red" => Color::Red,
+                        "green" => Color::Green,
+                        "blue" => Color::Blue,
+                        _ => {
+                            let message =
+                                format!("expected red, green or blue, got '{cube_color}'");
+                            return Err(AocError::at_rest(input, cube_color, message));
+                        }
+                    };
+                    map.insert(color, cube_count);
+                }
+
+                game.sets.push(map);
+            }
+            games.push(game);
+        }
+        Ok(games)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        Ok(part2::process(input))
+    }
+}
+
+pub struct Game {
+    id: i32,
+    sets: Vec<HashMap<Color, i32>>,
+}
+
+impl Game {
+    fn new(id: i32) -> Self {
+        Self {
+            id,
+            sets: Vec::new(),
+        }
+    }
+}
+
+#[derive(Hash, Eq, PartialEq, Debug)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
@@ -0,0 +1,55 @@
+use log::trace;
+
+use crate::{Color, Game};
+
+impl Game {
+    fn is_valid(&self) -> bool {
+        for set in &self.sets {
+            if let Some(red) = set.get(&Color::Red) {
+                if *red > 12 {
+                    return false;
+                }
+            }
+            if let Some(green) = set.get(&Color::Green) {
+                if *green > 13 {
+                    return false;
+                }
+            }
+            if let Some(blue) = set.get(&Color::Blue) {
+                if *blue > 14 {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+pub fn process(games: &[Game]) -> i32 {
+    for game in games {
+        trace!("{0}", game.id);
+        for set in &game.sets {
+            trace!(
+                "  r:{0:?}, g:{1:?}, b:{2:?}",
+                set.get(&Color::Red),
+                set.get(&Color::Green),
+                set.get(&Color::Blue)
+            );
+        }
+    }
+
+    count_total_valid_games(games)
+}
+
+fn count_total_valid_games(games: &[Game]) -> i32 {
+    // 12 red cubes
+    // 13 green cubes
+    // 14 blue cubes
+    let mut total = 0;
+    for game in games {
+        if game.is_valid() {
+            total += game.id;
+        }
+    }
+    total
+}
@@ -0,0 +1,60 @@
+use log::trace;
+
+use crate::{Color, Game};
+use std::collections::HashMap;
+
+impl Game {
+    // 1. get min cubes from each set
+    // 2. multiply them together
+    fn get_min_power(&self) -> i32 {
+        let mut min_r = None;
+        let mut min_g = None;
+        let mut min_b = None;
+
+        let set_min = |set: &HashMap<Color, i32>, color: &Color, min: &mut Option<i32>| {
+            if let Some(c) = set.get(color) {
+                if let Some(m) = min {
+                    if c > m {
+                        *min = Some(*c);
+                    }
+                } else {
+                    *min = Some(*c);
+                }
+            }
+        };
+
+        for set in &self.sets {
+            set_min(set, &Color::Red, &mut min_r);
+            set_min(set, &Color::Green, &mut min_g);
+            set_min(set, &Color::Blue, &mut min_b);
+        }
+
+        trace!("min ({0}): r:{min_r:?}, g:{min_g:?}, b:{min_b:?}", &self.id);
+
+        min_r.unwrap() * min_g.unwrap() * min_b.unwrap()
+    }
+}
+
+pub fn process(games: &[Game]) -> i32 {
+    // for game in &games {
+    //     println!("{0}", game.id);
+    //     for set in &game.sets {
+    //         println!(
+    //             "  r:{0:?}, g:{1:?}, b:{2:?}",
+    //             set.get(&Color::Red),
+    //             set.get(&Color::Green),
+    //             set.get(&Color::Blue)
+    //         );
+    //     }
+    // }
+
+    count_total_powers_in_games(games)
+}
+
+fn count_total_powers_in_games(games: &[Game]) -> i32 {
+    let mut total = 0;
+    for game in games {
+        total += game.get_min_power();
+    }
+    total
+}
@@ -0,0 +1,29 @@
+use common::{AocError, Solution};
+
+pub mod part1;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 20;
+
+    type Input = String;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Self::Output2, AocError> {
+        todo!()
+    }
+}
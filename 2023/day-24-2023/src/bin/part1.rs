@@ -1,22 +1,5 @@
-fn main() {
-    println!("Starting Process");
-    let now = std::time::Instant::now();
-    let input = include_str!("../../input.txt");
-    let output = process(input);
-    println!("Finished in {:?}", now.elapsed());
-    println!("Solution: {:?}", output);
-}
+use day_24_2023::{Day24, INPUT_PATH};
 
-fn process(input: &str) -> u32 {
-    todo!()
+fn main() {
+    common::solution::run::<Day24>(1, INPUT_PATH);
 }
-
-// #[cfg(test)]
-// mod tests {
-//     use super::*;
-//     #[test]
-//     fn day24_2023_part1() {
-//         let input = "";
-//         assert_eq!(process(input), 123);
-//     }
-// }
@@ -0,0 +1,132 @@
+use common::{matrix::Window, AocError, Matrix, Solution, Vec2};
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let mut builder = DigitBuilder::new();
+        for (y, line) in input.lines().enumerate() {
+            builder.complete();
+            for (x, char) in line.chars().enumerate() {
+                let cell = Cell::new(x, y);
+                if char.is_ascii_digit() {
+                    builder.extend(cell, char.to_digit(10).unwrap())
+                } else {
+                    builder.complete();
+                }
+            }
+        }
+
+        let map = Matrix::parse_grid(input, |char| {
+            if char.is_ascii_digit() {
+                CellKind::Digit
+            } else if char == '.' {
+                CellKind::Empty
+            } else {
+                CellKind::Symbol
+            }
+        })?;
+
+        Ok(Schematic {
+            digits: builder.digits,
+            map,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        Ok(part2::process(input))
+    }
+}
+
+/// The engine schematic: every number with its span, and the kind of every cell.
+pub struct Schematic {
+    digits: Vec<Digit>,
+    map: Matrix<CellKind>,
+}
+
+type Cell = Vec2<usize>;
+
+#[derive(PartialEq)]
+enum CellKind {
+    Empty,
+    Digit,
+    Symbol,
+}
+
+#[derive(PartialEq, Debug, Hash, Eq)]
+struct Digit {
+    start: Cell,
+    end: Cell,
+    total: u32,
+}
+
+impl Digit {
+    fn new(start: Cell, num: u32) -> Self {
+        Self {
+            start,
+            end: start,
+            total: num,
+        }
+    }
+
+    /// The digit and every cell around it that is inside the map.
+    fn surroundings<'a>(&self, map: &'a Matrix<CellKind>) -> Window<'a, CellKind> {
+        let top = self.start.y.saturating_sub(1);
+        let left = self.start.x.saturating_sub(1);
+        map.window(top, left, self.end.y + 2 - top, self.end.x + 2 - left)
+    }
+
+    fn add_number(&mut self, num: u32) {
+        self.total = format!("{0}{1}", &self.total.to_string(), num)
+            .parse()
+            .unwrap();
+    }
+}
+
+struct DigitBuilder {
+    current: Option<Digit>,
+    digits: Vec<Digit>,
+}
+
+impl DigitBuilder {
+    fn new() -> Self {
+        Self {
+            current: None,
+            digits: vec![],
+        }
+    }
+
+    fn extend(&mut self, cell: Cell, number: u32) {
+        if self.current.is_none() {
+            self.current = Some(Digit::new(cell, number));
+        } else {
+            let mut x = self.current.take().unwrap();
+            x.end = cell;
+            x.add_number(number);
+            self.current = Some(x);
+        }
+    }
+
+    fn complete(&mut self) {
+        if self.current.is_some() {
+            self.digits.push(self.current.take().unwrap());
+        }
+    }
+}
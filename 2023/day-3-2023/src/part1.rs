@@ -0,0 +1,24 @@
+use log::trace;
+
+use crate::{CellKind, Digit, Schematic};
+
+impl Digit {
+    fn symbol_in_bounds(&self, schematic: &Schematic) -> bool {
+        self.surroundings(&schematic.map)
+            .iter()
+            .any(|kind| *kind == CellKind::Symbol)
+    }
+}
+
+// get digits
+// check the cells around each for a symbol
+pub fn process(schematic: &Schematic) -> u32 {
+    let mut sum = 0;
+    for digit in &schematic.digits {
+        trace!("{digit:?}");
+        if digit.symbol_in_bounds(schematic) {
+            sum += digit.total;
+        }
+    }
+    sum
+}
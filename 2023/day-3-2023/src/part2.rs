@@ -0,0 +1,25 @@
+use crate::{Cell, CellKind, Schematic};
+use std::collections::HashMap;
+
+// get digits
+// collect the digits around each symbol
+pub fn process(schematic: &Schematic) -> u32 {
+    let Schematic { digits, map } = schematic;
+
+    let mut neighbours: HashMap<Cell, Vec<u32>> = HashMap::new();
+    for digit in digits {
+        let surroundings = digit.surroundings(map);
+        for (pos, kind) in surroundings.indexed_iter() {
+            if *kind == CellKind::Symbol {
+                let symbol = surroundings.origin() + pos;
+                neighbours.entry(symbol).or_default().push(digit.total);
+            }
+        }
+    }
+
+    neighbours
+        .values()
+        .filter(|totals| totals.len() >= 2)
+        .map(|totals| totals.iter().product::<u32>())
+        .sum()
+}
@@ -0,0 +1,71 @@
+use common::{error::parse_all, AocError, Solution};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{multispace0, multispace1, newline, u32},
+    multi::separated_list1,
+    sequence::{pair, preceded, separated_pair, tuple},
+    IResult,
+};
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_all(input, cards)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        Ok(part2::process(input))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Card {
+    winning_numbers: Vec<u32>,
+    numbers: Vec<u32>,
+}
+
+fn cards(input: &str) -> IResult<&str, Vec<Card>> {
+    separated_list1(newline, card)(input)
+}
+
+fn card(input: &str) -> IResult<&str, Card> {
+    let (input, _) = preceded(pair(tag("Card"), multispace1), u32)(input)?;
+    let (input, numbers) = preceded(pair(tag(":"), multispace1), number_sections)(input)?;
+    Ok((
+        input,
+        Card {
+            winning_numbers: numbers.0,
+            numbers: numbers.1,
+        },
+    ))
+}
+
+fn number_sections(input: &str) -> IResult<&str, (Vec<u32>, Vec<u32>)> {
+    separated_pair(
+        numbers,
+        tuple((multispace0, tag("|"), multispace0)),
+        numbers,
+    )(input)
+}
+
+fn numbers(input: &str) -> IResult<&str, Vec<u32>> {
+    separated_list1(multispace1, u32)(input)
+}
@@ -0,0 +1,20 @@
+use crate::Card;
+pub fn process(cards: &[Card]) -> u32 {
+    let cards_points = cards
+        .iter()
+        .flat_map(|card| {
+            card.numbers
+                .iter()
+                .filter(|num| card.winning_numbers.contains(num))
+                .fold(None, |acc: Option<u32>, _| {
+                    if let Some(last) = acc {
+                        Some(last * 2)
+                    } else {
+                        Some(1)
+                    }
+                })
+        })
+        .sum::<u32>();
+
+    cards_points
+}
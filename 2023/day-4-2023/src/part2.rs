@@ -0,0 +1,34 @@
+use crate::Card;
+use std::collections::BTreeMap;
+
+// answer: 5667240
+pub fn process(cards: &[Card]) -> u32 {
+    // parse cards
+    // track how many copies cards have (card_idx -> copies)
+    let mut card_idx_copies: BTreeMap<usize, u32> = BTreeMap::new();
+
+    for (card_idx, card) in cards.iter().enumerate() {
+        // count wins of a card including any of its copies
+        let card_copies = card_idx_copies.get(&card_idx).unwrap_or(&0);
+        let card_count: u32 = 1 + card_copies;
+        for _ in 0..card_count {
+            // count wins
+            let wins = card
+                .numbers
+                .iter()
+                .filter(|num| card.winning_numbers.contains(num))
+                .count();
+
+            // for any wins insert that many copies of next cards
+            let wins_range = 1..=wins;
+            for idx in wins_range {
+                card_idx_copies
+                    .entry(card_idx + idx)
+                    .and_modify(|copies| *copies += 1)
+                    .or_insert(1);
+            }
+        }
+    }
+
+    cards.len() as u32 + card_idx_copies.values().sum::<u32>()
+}
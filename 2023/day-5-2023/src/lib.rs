@@ -0,0 +1,139 @@
+use common::{error::parse_all, AocError, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alphanumeric1, multispace0, multispace1, newline, space1},
+    combinator::recognize,
+    multi::{many1, separated_list1},
+    sequence::{delimited, pair},
+    IResult,
+};
+use std::ops::Range;
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Input = Data;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let data = parse_all(input, parse_data)?;
+        if let Some(name) = MAPS
+            .iter()
+            .find(|name| !data.maps.iter().any(|map| map.name == **name))
+        {
+            return Err(AocError::invalid(format!("missing the {name} map")));
+        }
+        Ok(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        Ok(part2::process(input))
+    }
+}
+
+/// Every map a seed goes through, in order.
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+#[derive(Debug)]
+pub struct Data {
+    seeds: Vec<u32>,
+    maps: Vec<Map>,
+}
+
+#[derive(Debug)]
+struct Map {
+    name: String,
+    details: Vec<Details>,
+}
+
+#[derive(Debug)]
+struct Details {
+    source: Range<u32>,
+    destination: Range<u32>,
+}
+
+impl Map {
+    fn remap(&self, seed: u32) -> u32 {
+        for detail in &self.details {
+            if let Some(dst) = detail.try_remap(seed) {
+                return dst;
+            }
+        }
+        seed
+    }
+}
+
+impl Details {
+    fn try_remap(&self, value: u32) -> Option<u32> {
+        if self.source.contains(&value) {
+            let offset = value - self.source.start;
+            return Some(self.destination.start + offset);
+        }
+        None
+    }
+}
+
+fn parse_data(input: &str) -> IResult<&str, Data> {
+    use nom::character::complete::u32;
+    let (input, seeds) =
+        delimited(tag("seeds: "), separated_list1(space1, u32), multispace1)(input)?;
+    let (input, maps) = separated_list1(newline, parse_map)(input)?;
+
+    Ok((input, Data { seeds, maps }))
+}
+
+fn parse_map(input: &str) -> IResult<&str, Map> {
+    let (input, _) = multispace0(input)?;
+    let (input, map_name) = recognize(many1(alt((alphanumeric1, tag("-")))))(input)?;
+    let (input, _) = pair(tag(" map:"), newline)(input)?;
+    let (input, details) = separated_list1(newline, parse_details)(input)?;
+
+    Ok((
+        input,
+        Map {
+            name: map_name.to_owned(),
+            details,
+        },
+    ))
+}
+
+fn parse_details(input: &str) -> IResult<&str, Details> {
+    use nom::character::complete::u32;
+    let (input, digits) = separated_list1(space1, u32)(input)?;
+    Ok((
+        input,
+        Details {
+            source: Range {
+                start: digits[1],
+                end: digits[1] + digits[2],
+            },
+            destination: Range {
+                start: digits[0],
+                end: digits[0] + digits[2],
+            },
+        },
+    ))
+}
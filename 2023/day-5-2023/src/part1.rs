@@ -0,0 +1,44 @@
+use log::trace;
+
+use crate::Data;
+use std::collections::BTreeMap;
+
+impl Data {
+    fn best_location_for_all_seeds(&self) -> u32 {
+        let mut named_maps = BTreeMap::new();
+        for map in self.maps.iter() {
+            named_maps.insert(map.name.to_owned(), map);
+        }
+
+        let mut locations = vec![];
+        for seed in &self.seeds {
+            let soil = named_maps.get("seed-to-soil").unwrap().remap(*seed);
+            let fertilizer = named_maps.get("soil-to-fertilizer").unwrap().remap(soil);
+            let water = named_maps
+                .get("fertilizer-to-water")
+                .unwrap()
+                .remap(fertilizer);
+            let light = named_maps.get("water-to-light").unwrap().remap(water);
+            let temperature = named_maps.get("light-to-temperature").unwrap().remap(light);
+            let humidity = named_maps
+                .get("temperature-to-humidity")
+                .unwrap()
+                .remap(temperature);
+            let location = named_maps
+                .get("humidity-to-location")
+                .unwrap()
+                .remap(humidity);
+
+            trace!("seed: {seed}, soil: {soil}, fertilizer: {fertilizer}, water: {water}, light: {light}, temperature: {temperature}, humidity: {humidity}, location: {location}");
+
+            locations.push(location);
+        }
+
+        *locations.iter().min().unwrap()
+    }
+}
+
+pub fn process(data: &Data) -> u32 {
+    trace!("{data:?}");
+    data.best_location_for_all_seeds()
+}
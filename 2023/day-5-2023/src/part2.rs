@@ -0,0 +1,69 @@
+use log::trace;
+
+use crate::{Data, MAPS};
+use indicatif::ProgressBar;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::ops::Range;
+
+impl Data {
+    fn best_location_for_all_seed_ranges(&self) -> u32 {
+        // construct seed ranges
+        let seed_ranges: Vec<_> = self
+            .seeds
+            .chunks(2)
+            .map(|seed| Range {
+                start: seed[0],
+                end: seed[0] + seed[1],
+            })
+            .collect();
+
+        // order maps by name
+        let mut maps_iter = self.maps.iter();
+        let ordered_maps = MAPS
+            .iter()
+            .map(|name| {
+                maps_iter
+                    .find(|map| map.name == *name)
+                    .unwrap_or_else(|| panic!("map '{}' not found", name))
+            })
+            .collect::<Vec<_>>();
+
+        // count all seeds so we could optimize progress bar with skips
+        let progress_seeds: u64 = seed_ranges.len() as u64
+            + seed_ranges
+                .iter()
+                .fold(0, |count, seed_range| count + seed_range.len() as u64);
+
+        // report progress 100 (or less) times over all seeds
+        let progress_skips: u64 = if progress_seeds > 100 {
+            progress_seeds / 100
+        } else {
+            progress_seeds
+        };
+
+        let pb = ProgressBar::new(progress_seeds);
+
+        // find the mind location using parallel iterator
+        let min_location = seed_ranges
+            .into_par_iter()
+            .flat_map(|range| range.into_iter())
+            .map(|seed| {
+                if (seed as u64).is_multiple_of(progress_skips) {
+                    pb.inc(progress_skips);
+                }
+                let x = ordered_maps
+                    .iter()
+                    .fold(seed, |acc, ord_map| ord_map.remap(acc));
+                x
+            })
+            .min()
+            .unwrap();
+
+        min_location
+    }
+}
+
+pub fn process(data: &Data) -> u32 {
+    trace!("{data:?}");
+    data.best_location_for_all_seed_ranges()
+}
@@ -0,0 +1,94 @@
+use common::{error::parse_all, AocError, Solution};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{multispace1, newline},
+    multi::separated_list1,
+    sequence::preceded,
+    IResult,
+};
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    type Input = Sheet;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let sheet = parse_all(input, sheet)?;
+        if sheet.times.len() != sheet.distances.len() {
+            return Err(AocError::invalid(
+                "every race needs both a time and a distance",
+            ));
+        }
+        Ok(sheet)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        Ok(part2::process(input))
+    }
+}
+
+/// The raw columns of the race sheet.
+#[derive(Debug)]
+pub struct Sheet {
+    times: Vec<u32>,
+    distances: Vec<u32>,
+}
+
+#[derive(Debug)]
+struct Race {
+    time: u64,     // time for race
+    distance: u64, // best record
+}
+
+impl Race {
+    fn count_record_beats(&self) -> u64 {
+        let mut beats = 0;
+        for charge_time in 0..self.time {
+            let dist = self.get_dist_for_charge_time(charge_time);
+            if dist > self.distance {
+                beats += 1;
+            }
+        }
+        beats
+    }
+
+    fn get_dist_for_charge_time(&self, charge_time: u64) -> u64 {
+        let speed = charge_time;
+        let time = self.time - charge_time;
+        speed * time
+    }
+}
+
+fn sheet(input: &str) -> IResult<&str, Sheet> {
+    use nom::character::complete::u32;
+
+    // Time: 1 2 3
+    let time_tag = preceded(tag("Time:"), multispace1);
+    let time_vec = separated_list1(multispace1, u32);
+    let (input, times) = preceded(time_tag, time_vec)(input)?;
+
+    // \n
+    let (input, _) = newline(input)?;
+
+    // Distance: 1 2 3
+    let dist_tag = preceded(tag("Distance:"), multispace1);
+    let dist_vec = separated_list1(multispace1, u32);
+    let (input, distances) = preceded(dist_tag, dist_vec)(input)?;
+
+    Ok((input, Sheet { times, distances }))
+}
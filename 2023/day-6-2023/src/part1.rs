@@ -0,0 +1,23 @@
+use log::trace;
+
+use crate::{Race, Sheet};
+
+// determine the number of ways you can beat the record in each race
+// answer: 512295
+pub fn process(sheet: &Sheet) -> u64 {
+    let races = sheet
+        .times
+        .iter()
+        .zip(&sheet.distances)
+        .map(|x| Race {
+            time: *x.0 as u64,
+            distance: *x.1 as u64,
+        })
+        .collect::<Vec<_>>();
+
+    trace!("{races:?}");
+
+    races
+        .into_iter()
+        .fold(1, |acc, race| acc * race.count_record_beats())
+}
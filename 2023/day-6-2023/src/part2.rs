@@ -0,0 +1,19 @@
+use crate::{Race, Sheet};
+
+// determine the number of ways you can beat the record in one race
+// answer: 36530883
+pub fn process(sheet: &Sheet) -> u64 {
+    // concat values [7, 15, 30] into "71530" and then parse it into a number: 71530
+    let concat = |v: &[u32]| {
+        v.iter()
+            .map(|num| num.to_string())
+            .collect::<String>()
+            .parse::<u64>()
+            .unwrap()
+    };
+
+    let time = concat(&sheet.times);
+    let distance = concat(&sheet.distances);
+    let race = Race { time, distance };
+    race.count_record_beats()
+}
@@ -0,0 +1,58 @@
+use common::{AocError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+
+    type Input = Vec<Play>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        part1::process(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        part2::process(input)
+    }
+}
+
+/// A hand of cards as written on the sheet, before each part decides what `J` means.
+#[derive(Debug)]
+pub struct Play {
+    cards: String,
+    bid: u32,
+}
+
+fn parse(input: &str) -> Result<Vec<Play>, AocError> {
+    input
+        .lines()
+        .map(|line| {
+            let Some((cards, bid)) = line.split_once(' ') else {
+                return Err(AocError::at_rest(input, line, "expected '<cards> <bid>'"));
+            };
+            if let Some(i) = cards.find(|card| !"AKQJT98765432".contains(card)) {
+                return Err(AocError::at_rest(input, &cards[i..], "expected a card"));
+            }
+
+            Ok(Play {
+                cards: cards.to_string(),
+                bid: bid
+                    .parse()
+                    .map_err(|_| AocError::at_rest(input, bid, "expected a bid"))?,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,119 @@
+use common::{error::parse_all, AocError, Solution};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alphanumeric1, multispace1, newline, one_of},
+    combinator::map,
+    multi::{many1, separated_list1},
+    sequence::{delimited, separated_pair, terminated},
+    IResult,
+};
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+
+    type Input = Data;
+    type Output1 = u32;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_all(input, parse)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        part1::process(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        Ok(part2::process(input))
+    }
+}
+
+#[derive(Debug)]
+pub struct Data {
+    instructions: Vec<Instruction>,
+    nodes: Vec<Node>,
+}
+
+#[derive(Debug)]
+enum Instruction {
+    Left,
+    Right,
+}
+
+impl From<char> for Instruction {
+    fn from(value: char) -> Self {
+        match value {
+            'L' => Self::Left,
+            'R' => Self::Right,
+            _ => panic!("could not parse instruction from: {value}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    id: Tag,
+    left: Tag,
+    right: Tag,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord)]
+struct Tag(String);
+
+impl Tag {
+    // note: there's no need to create a wrapper over a string as it already contains String::ends_with(...), but I'll keep this as a lesson for the future.
+    fn ends(&self, value: char) -> bool {
+        self.0.ends_with(value)
+    }
+}
+
+impl From<&str> for Tag {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Data> {
+    let (input, instructions) =
+        terminated(many1(map(one_of("LR"), Instruction::from)), multispace1)(input)?;
+
+    let (input, nodes) = separated_list1(newline, parse_node)(input)?;
+
+    Ok((
+        input,
+        Data {
+            instructions,
+            nodes,
+        },
+    ))
+}
+
+fn parse_node(input: &str) -> IResult<&str, Node> {
+    use nom::character::complete::char;
+
+    let (input, id) = alphanumeric1(input)?;
+    let (input, _) = tag(" = ")(input)?;
+    let (input, (left, right)) = delimited(
+        char('('),
+        separated_pair(alphanumeric1, tag(", "), alphanumeric1),
+        char(')'),
+    )(input)?;
+
+    Ok((
+        input,
+        Node {
+            id: id.into(),
+            left: left.into(),
+            right: right.into(),
+        },
+    ))
+}
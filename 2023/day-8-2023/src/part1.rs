@@ -0,0 +1,42 @@
+use log::trace;
+use std::collections::BTreeMap;
+
+use common::AocError;
+
+use crate::{Data, Instruction, Node, Tag};
+
+// 14257
+pub fn process(data: &Data) -> Result<u32, AocError> {
+    trace!("{data:?}");
+
+    // create a map from id -> node for quick access
+    let map = data
+        .nodes
+        .iter()
+        .map(|x| (x.id.clone(), x))
+        .collect::<BTreeMap<Tag, &Node>>();
+
+    let mut steps = 0;
+    let mut node = map
+        .get(&"AAA".into())
+        .ok_or_else(|| AocError::invalid("no starting node called AAA"))?;
+
+    for instruction in data.instructions.iter().cycle() {
+        steps += 1;
+
+        let node_id = match instruction {
+            Instruction::Left => &node.left,
+            Instruction::Right => &node.right,
+        };
+
+        node = map
+            .get(node_id)
+            .ok_or_else(|| AocError::invalid(format!("no node called {}", node_id.0)))?;
+
+        if node.id.0 == "ZZZ" {
+            break;
+        }
+    }
+
+    Ok(steps)
+}
@@ -0,0 +1,89 @@
+use log::trace;
+use std::collections::BTreeMap;
+
+use crate::{Data, Instruction, Node, Tag};
+
+// 16_187_743_689_077
+pub fn process(data: &Data) -> usize {
+    trace!("{data:?}");
+
+    // create a map from id -> node for quick access
+    let map = data
+        .nodes
+        .iter()
+        .map(|x| (x.id.clone(), x))
+        .collect::<BTreeMap<Tag, &Node>>();
+
+    // find loop end for all nodes that start with A and end with E
+    let loop_ends = map
+        .values()
+        .filter(|x| x.id.ends('A'))
+        .map(|node| {
+            // process all instructions for a single instance of a loop
+            let mut current_node = *node;
+            data.instructions
+                .iter()
+                .cycle()
+                .position(|instruction| {
+                    // get next node by matching on instruction
+                    let next_node = map
+                        .get(match instruction {
+                            Instruction::Left => &current_node.left,
+                            Instruction::Right => &current_node.right,
+                        })
+                        .expect("node must exist");
+                    // when found a match – return it
+                    let done = next_node.id.ends('Z');
+                    if !done {
+                        // or keep going – next!
+                        current_node = next_node;
+                    }
+                    done
+                })
+                .map(|x| x + 1)
+                .expect("should find an end to the loop")
+        })
+        .collect::<Vec<usize>>();
+
+    // find least common multiple for all loop ends
+    lcm_multiple(&loop_ends)
+}
+
+/// LCM (Least Common Multiple) of multiple numbers is the smallest number that is evenly divisible by all numbers in the set.
+fn lcm_multiple(numbers: &[usize]) -> usize {
+    let mut result = numbers[0];
+    for &number in &numbers[1..] {
+        result = lcm(number, result);
+    }
+    result
+}
+
+/// LCM (Least Common Multiple) of two numbers is the smallest number that is evenly divisible by all numbers in the set.
+///
+/// For example common multiples of 4 and 6 are 12, 24 and 36, but the lowest of those is 12
+///
+/// `lcm(4, 6) = 12`
+fn lcm(a: usize, b: usize) -> usize {
+    (a * b) / gcd(a, b)
+}
+
+/// GCD (Greatest Common Divisor) of two or more numbers is the greatest common factor number that divides them, exactly.
+///
+/// `gcd(8, 12) = 4`
+fn gcd(a: usize, b: usize) -> usize {
+    if a == 0 {
+        b
+    } else {
+        gcd(b % a, a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{gcd, lcm};
+    #[test]
+    fn day8_2023_lcm() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(gcd(8, 12), 4);
+    }
+}
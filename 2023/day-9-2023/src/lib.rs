@@ -0,0 +1,40 @@
+use common::{error::parse_all, AocError, Solution};
+use nom::{
+    character::complete::{newline, space1},
+    multi::separated_list1,
+    IResult,
+};
+
+pub mod part1;
+pub mod part2;
+
+/// Read at runtime, see `common::input` for the other places an input can come from.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<i32>>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_all(input, parse)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError> {
+        Ok(part2::process(input))
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Vec<i32>>> {
+    use nom::character::complete::i32;
+    separated_list1(newline, separated_list1(space1, i32))(input)
+}
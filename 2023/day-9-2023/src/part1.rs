@@ -0,0 +1,30 @@
+use itertools::Itertools;
+
+// 1798691765
+pub fn process(sequences: &[Vec<i32>]) -> i32 {
+    sequences
+        .iter()
+        .map(|seq| predict_next_element(seq))
+        .sum::<i32>()
+}
+
+/// # Example:
+/// ```text
+/// 1   3   6  10  15  21 //<- seq (original)
+///   2   3   4   5   6   //<- deltas
+///     1   1   1   1
+///       0   0   0
+/// ```
+fn predict_next_element(seq: &[i32]) -> i32 {
+    if seq.iter().all(|x| *x == 0) {
+        return 0;
+    }
+
+    let last = seq
+        .last()
+        .expect("should have at least one element in starting sequence");
+
+    let differences = seq.windows(2).map(|x| x[1] - x[0]).collect_vec();
+
+    last + predict_next_element(&differences)
+}
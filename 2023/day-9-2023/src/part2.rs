@@ -0,0 +1,30 @@
+use itertools::Itertools;
+
+// 1104
+pub fn process(sequences: &[Vec<i32>]) -> i32 {
+    sequences
+        .iter()
+        .map(|seq| predict_prev_element(seq))
+        .sum::<i32>()
+}
+
+/// # Example:
+/// ```text
+/// 1   3   6  10  15  21 //<- seq (original)
+///   2   3   4   5   6   //<- deltas
+///     1   1   1   1
+///       0   0   0
+/// ```
+fn predict_prev_element(seq: &[i32]) -> i32 {
+    if seq.iter().all(|x| *x == 0) {
+        return 0;
+    }
+
+    let first = seq
+        .first()
+        .expect("should have at least one element in starting sequence");
+
+    let differences = seq.windows(2).map(|x| x[1] - x[0]).collect_vec();
+
+    first - predict_prev_element(&differences)
+}
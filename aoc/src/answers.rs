@@ -0,0 +1,200 @@
+use std::{fmt::Display, path::PathBuf};
+
+use serde::Deserialize;
+
+/// Known answers for a year, read from `<year>/answers.toml`:
+///
+/// ```toml
+/// [[day]]
+/// day = 17
+/// input = "6c1b0f0e2a9d4f31" # optional, pins the answers to one input
+/// part1 = 1260
+/// part2 = 1416
+/// ```
+///
+/// Answers without an `input` hash apply to any input.
+#[derive(Debug, Default, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "day")]
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Entry {
+    day: u8,
+    input: Option<String>,
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+/// Answers are compared as text, so they can be written as numbers or strings.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn path(year: u16) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(year.to_string())
+            .join("answers.toml")
+    }
+
+    /// A year without an answers file has no known answers.
+    pub fn load(year: u16) -> Result<Self, String> {
+        let path = Self::path(year);
+        match std::fs::read_to_string(&path) {
+            Ok(text) => Self::parse(&text).map_err(|err| format!("{}: {err}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("{}: {err}", path.display())),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(text)
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.entries.iter().map(|entry| entry.day)
+    }
+
+    /// An answer pinned to this exact input wins over one for any input.
+    pub fn expected(&self, day: u8, part: u8, input_hash: &str) -> Option<String> {
+        let entries = self.entries.iter().filter(|entry| entry.day == day);
+        let pinned = entries
+            .clone()
+            .filter(|entry| entry.input.as_deref() == Some(input_hash));
+        let any = entries.filter(|entry| entry.input.is_none());
+
+        pinned
+            .chain(any)
+            .find_map(|entry| match part {
+                1 => entry.part1.as_ref(),
+                2 => entry.part2.as_ref(),
+                _ => None,
+            })
+            .map(Answer::to_string)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn check(expected: Option<String>, output: &str) -> Self {
+        match expected {
+            Some(expected) if expected == output => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+/// FNV-1a of the input, stable across platforms and toolchains unlike `std::hash`.
+///
+/// Trailing whitespace is ignored, so a missing final newline doesn't change the hash.
+pub fn input_hash(input: &str) -> String {
+    let hash = input
+        .trim_end()
+        .bytes()
+        .fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expected() {
+        let answers = Answers::parse(
+            r#"
+[[day]]
+day = 17
+part1 = 102
+part2 = "94"
+
+[[day]]
+day = 17
+input = "00000000000000aa"
+part1 = 1260
+
+[[day]]
+day = 19
+input = "00000000000000bb"
+part1 = 332145
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            answers.expected(17, 1, "00000000000000aa").as_deref(),
+            Some("1260")
+        );
+        // falls back to the answer for any input when the pinned one has no part 2
+        assert_eq!(
+            answers.expected(17, 2, "00000000000000aa").as_deref(),
+            Some("94")
+        );
+        assert_eq!(
+            answers.expected(17, 1, "00000000000000cc").as_deref(),
+            Some("102")
+        );
+        assert_eq!(
+            answers.expected(19, 1, "00000000000000bb").as_deref(),
+            Some("332145")
+        );
+        assert_eq!(answers.expected(19, 1, "00000000000000cc"), None);
+        assert_eq!(answers.expected(19, 2, "00000000000000bb"), None);
+        assert_eq!(answers.expected(1, 1, "00000000000000bb"), None);
+    }
+
+    #[test]
+    fn answers_files_match_registry() {
+        for year in [2015, 2022, 2023] {
+            let answers = Answers::load(year).unwrap();
+            for day in answers.days() {
+                let registered = crate::registry::find(year, day)
+                    .unwrap_or_else(|| panic!("{year} day {day} has answers but isn't registered"));
+                assert!(!registered.is_stub());
+            }
+        }
+    }
+
+    #[test]
+    fn verdict() {
+        assert_eq!(Verdict::check(Some("1".into()), "1"), Verdict::Pass);
+        assert_eq!(
+            Verdict::check(Some("1".into()), "2"),
+            Verdict::Fail {
+                expected: "1".into()
+            }
+        );
+        assert_eq!(Verdict::check(None, "2"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_eq!(input_hash("a\n"), input_hash("a"));
+    }
+}
@@ -0,0 +1,226 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use common::AocError;
+use serde::{Deserialize, Serialize};
+
+use crate::Day;
+
+/// A result that is this much slower than its baseline is flagged as a regression.
+pub const REGRESSION_THRESHOLD: f64 = 0.10;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// Most samples taken per phase.
+    pub reps: usize,
+    /// No more samples are started once a phase has run this long, so heavy days like
+    /// 2023 day 5 part 2 still finish, with at least one sample.
+    pub budget: Duration,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            reps: 25,
+            budget: Duration::from_secs(5),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Nearest-rank percentiles, `samples` can't be empty.
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        samples.sort();
+        let rank = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+        Self {
+            samples: samples.len(),
+            min: samples[0],
+            median: rank(50),
+            p95: rank(95),
+        }
+    }
+
+    fn sample(options: Options, mut f: impl FnMut() -> Duration) -> Self {
+        let start = Instant::now();
+        let mut samples = Vec::new();
+        while samples.is_empty()
+            || (samples.len() < options.reps && start.elapsed() < options.budget)
+        {
+            samples.push(f());
+        }
+        Self::new(samples)
+    }
+}
+
+/// One timed phase of a day, as saved to and loaded from JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    /// `parse`, `part1` or `part2`.
+    pub phase: String,
+    pub samples: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Record {
+    fn new(day: &Day, phase: &str, stats: Stats) -> Self {
+        let ns = |d: Duration| d.as_nanos().try_into().unwrap_or(u64::MAX);
+        Self {
+            year: day.year,
+            day: day.day,
+            phase: phase.to_owned(),
+            samples: stats.samples,
+            min_ns: ns(stats.min),
+            median_ns: ns(stats.median),
+            p95_ns: ns(stats.p95),
+        }
+    }
+
+    fn is_same(&self, other: &Record) -> bool {
+        (self.year, self.day, &self.phase) == (other.year, other.day, &other.phase)
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let d = Duration::from_nanos;
+        write!(
+            f,
+            "{} day {} {:<5}  min {:>10.2?}  median {:>10.2?}  p95 {:>10.2?}  ({} runs)",
+            self.year,
+            self.day,
+            self.phase,
+            d(self.min_ns),
+            d(self.median_ns),
+            d(self.p95_ns),
+            self.samples
+        )
+    }
+}
+
+/// Times parsing and each solved part separately, parts share one parsed input.
+///
+/// Each phase runs once untimed first, so an input the day rejects is an error.
+pub fn day(day: &Day, input: &str, options: Options) -> Result<Vec<Record>, AocError> {
+    let parsed = day.puzzle.parse(input)?;
+    let parse = Stats::sample(options, || {
+        let now = Instant::now();
+        let parsed = black_box(day.puzzle.parse(black_box(input)));
+        let elapsed = now.elapsed();
+        drop(parsed);
+        elapsed
+    });
+
+    let mut records = vec![Record::new(day, "parse", parse)];
+    for &part in day.parts {
+        day.puzzle.part(part, parsed.as_ref())?;
+        let stats = Stats::sample(options, || {
+            let now = Instant::now();
+            let _ = black_box(day.puzzle.part(part, black_box(parsed.as_ref())));
+            now.elapsed()
+        });
+        records.push(Record::new(day, &format!("part{part}"), stats));
+    }
+    Ok(records)
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Report {
+    pub records: Vec<Record>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text =
+            std::fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+        serde_json::from_str(&text).map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).expect("records always serialize");
+        std::fs::write(path, json + "\n").map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    /// How the median of `record` moved since this baseline, `0.25` is 25% slower.
+    pub fn change(&self, record: &Record) -> Option<f64> {
+        self.records
+            .iter()
+            .find(|baseline| baseline.is_same(record))
+            .filter(|baseline| baseline.median_ns > 0)
+            .map(|baseline| record.median_ns as f64 / baseline.median_ns as f64 - 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::new((1..=20).rev().map(ms).collect());
+        assert_eq!(stats.samples, 20);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(10));
+        assert_eq!(stats.p95, ms(19));
+
+        let stats = Stats::new(vec![ms(7)]);
+        assert_eq!((stats.min, stats.median, stats.p95), (ms(7), ms(7), ms(7)));
+    }
+
+    #[test]
+    fn sample_respects_budget() {
+        let options = Options {
+            reps: 1000,
+            budget: Duration::ZERO,
+        };
+        let stats = Stats::sample(options, || Duration::from_millis(1));
+        assert_eq!(stats.samples, 1);
+
+        let options = Options {
+            reps: 3,
+            budget: Duration::from_secs(60),
+        };
+        let stats = Stats::sample(options, || Duration::from_millis(1));
+        assert_eq!(stats.samples, 3);
+    }
+
+    #[test]
+    fn change() {
+        let day = crate::registry::find(2023, 17).unwrap();
+        let ms = Duration::from_millis;
+        let stats = |median| Stats {
+            samples: 1,
+            min: ms(median),
+            median: ms(median),
+            p95: ms(median),
+        };
+
+        let baseline = Report {
+            records: vec![Record::new(day, "part1", stats(100))],
+        };
+        let slower = Record::new(day, "part1", stats(125));
+        assert_eq!(baseline.change(&slower), Some(0.25));
+        assert_eq!(baseline.change(&Record::new(day, "part2", stats(1))), None);
+
+        let json = serde_json::to_string(&baseline).unwrap();
+        let loaded: Report = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.records, baseline.records);
+    }
+}
@@ -0,0 +1,141 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use common::solution::Params;
+use serde::Deserialize;
+
+use crate::{answers::Answer, Day};
+
+/// A small input with known answers, read from a day's `examples/<name>.txt` and the
+/// `examples/<name>.toml` next to it:
+///
+/// ```toml
+/// part1 = 374
+/// part2 = 1030
+///
+/// [params] # optional, see `common::solution::Params`
+/// expansion = 10
+/// ```
+///
+/// Either part can be left out when the example only applies to the other one.
+#[derive(Debug)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub params: Params,
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expected {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+    #[serde(default)]
+    params: BTreeMap<String, toml::Value>,
+}
+
+impl Example {
+    pub fn expected(&self, part: u8) -> Option<String> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+        .map(Answer::to_string)
+    }
+
+    fn load(txt: &Path) -> Result<Self, String> {
+        let toml = txt.with_extension("toml");
+        let name = txt
+            .file_stem()
+            .expect("example files have a name")
+            .to_string_lossy()
+            .into_owned();
+        let input = fs::read_to_string(txt).map_err(|err| format!("{}: {err}", txt.display()))?;
+        let expected = fs::read_to_string(&toml)
+            .map_err(|err| format!("{}: {err}", toml.display()))
+            .and_then(|text| {
+                toml::from_str::<Expected>(&text)
+                    .map_err(|err| format!("{}: {err}", toml.display()))
+            })?;
+
+        if expected.part1.is_none() && expected.part2.is_none() {
+            return Err(format!("{}: expects neither part", toml.display()));
+        }
+
+        let mut params = Params::new();
+        for (key, value) in expected.params {
+            // strings as written, not quoted the way toml displays them
+            let value = match value {
+                toml::Value::String(value) => value,
+                value => value.to_string(),
+            };
+            params.insert(&key, value);
+        }
+
+        Ok(Self {
+            name,
+            input,
+            params,
+            part1: expected.part1,
+            part2: expected.part2,
+        })
+    }
+}
+
+impl Day {
+    /// Every example in the day's `examples` directory, sorted by name.
+    ///
+    /// A day without the directory has no examples.
+    pub fn examples(&self) -> Result<Vec<Example>, String> {
+        let dir = self.dir().join("examples");
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(format!("{}: {err}", dir.display())),
+        };
+
+        let mut paths = Vec::new();
+        for entry in entries {
+            let path = entry
+                .map_err(|err| format!("{}: {err}", dir.display()))?
+                .path();
+            match path.extension().and_then(|ext| ext.to_str()) {
+                Some("txt") => paths.push(path),
+                Some("toml") if !path.with_extension("txt").exists() => {
+                    return Err(format!("{}: has no example input", path.display()))
+                }
+                _ => {}
+            }
+        }
+        paths.sort();
+
+        paths.iter().map(|path| Example::load(path)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+
+    #[test]
+    fn examples() {
+        let day = registry::find(2023, 11).unwrap();
+        let examples = day.examples().unwrap();
+        assert!(!examples.is_empty());
+
+        let example = examples
+            .iter()
+            .find(|example| example.params.get::<usize>("expansion") == Some(10))
+            .unwrap();
+        assert_eq!(example.expected(2).as_deref(), Some("1030"));
+    }
+
+    #[test]
+    fn stubs_have_no_examples() {
+        for day in registry::DAYS.iter().filter(|d| d.is_stub()) {
+            assert!(day.examples().unwrap().is_empty(), "{}", day.package());
+        }
+    }
+}
@@ -0,0 +1,8 @@
+pub mod answers;
+pub mod bench;
+pub mod examples;
+pub mod registry;
+pub mod scaffold;
+pub mod summary;
+
+pub use registry::{Day, Puzzle, DAYS};
@@ -0,0 +1,369 @@
+use aoc::{
+    answers::{input_hash, Answers, Verdict},
+    bench::{self, Report, REGRESSION_THRESHOLD},
+    registry, scaffold,
+    summary::{Status, Summary},
+    Day,
+};
+use common::{logging, AocError};
+use log::{warn, LevelFilter};
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    path::Path,
+    process::ExitCode,
+    time::Duration,
+};
+
+const USAGE: &str = "usage: aoc <year> [day] [part] [--input <path>|-] [-q|-v|-vv|-vvv]
+       aoc verify [year] [day] [--input <path>|-]
+       aoc bench [year] [day] [--input <path>|-] [--reps <n>] [--budget <secs>]
+                 [--save <file.json>] [--baseline <file.json>]
+       aoc summary <year> [--json]
+       aoc list [year]
+       aoc new <year> <day>";
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    logging::init(logging::verbosity(&mut args));
+    let mut args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    // `--input <path>` swaps the day's input for another file, or stdin with `-`
+    let result = take_option(&mut args, "--input").and_then(|input| {
+        if args.first() == Some(&"bench") {
+            return bench_command(&mut args, input);
+        }
+
+        match args.as_slice() {
+            [_] | ["list", _] | ["verify"] | ["verify", _] | ["summary", ..] if input.is_some() => {
+                Err(single_day_input())
+            }
+            ["list"] => list(None),
+            ["verify"] => verify(None, None, None),
+            ["verify", year] => parse(year, "year").and_then(|year| verify(Some(year), None, None)),
+            ["verify", year, day] => parse(year, "year")
+                .and_then(|year| Ok((year, parse(day, "day")?)))
+                .and_then(|(year, day)| verify(Some(year), Some(day), input)),
+            ["list", year] => parse(year, "year").and_then(|year| list(Some(year))),
+            ["summary", year] => parse(year, "year").and_then(|year| summary(year, false)),
+            ["summary", year, "--json"] | ["summary", "--json", year] => {
+                parse(year, "year").and_then(|year| summary(year, true))
+            }
+            ["new", year, day] => parse(year, "year")
+                .and_then(|year| Ok((year, parse(day, "day")?)))
+                .and_then(|(year, day)| new_day(year, day)),
+            [year] => parse(year, "year").and_then(run_year),
+            [year, day] => parse(year, "year")
+                .and_then(|year| Ok((year, parse(day, "day")?)))
+                .and_then(|(year, day)| run_day(find(year, day)?, &[1, 2], input)),
+            [year, day, part] => parse(year, "year")
+                .and_then(|year| Ok((year, parse(day, "day")?, parse(part, "part")?)))
+                .and_then(|(year, day, part)| run_day(find(year, day)?, &[part], input)),
+            _ => Err(USAGE.to_owned()),
+        }
+    });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => fail(&err),
+    }
+}
+
+/// `-q` prints the bare answers, nothing else.
+fn quiet() -> bool {
+    log::max_level() == LevelFilter::Off
+}
+
+fn single_day_input() -> String {
+    format!("--input needs a single day, a whole year reads each day's own input\n{USAGE}")
+}
+
+/// Removes `name <value>` from the arguments, wherever it is.
+fn take_option<'a>(args: &mut Vec<&'a str>, name: &str) -> Result<Option<&'a str>, String> {
+    match args.iter().position(|arg| *arg == name) {
+        Some(i) if i + 1 < args.len() => {
+            let value = args.remove(i + 1);
+            args.remove(i);
+            Ok(Some(value))
+        }
+        Some(_) => Err(format!("{name} needs a value\n{USAGE}")),
+        None => Ok(None),
+    }
+}
+
+fn fail(err: &str) -> ExitCode {
+    eprintln!("{err}");
+    ExitCode::FAILURE
+}
+
+fn parse<T: std::str::FromStr>(value: &str, what: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("could not parse {what} from '{value}'\n{USAGE}"))
+}
+
+fn find(year: u16, day: u8) -> Result<&'static Day, String> {
+    registry::find(year, day).ok_or_else(|| format!("no day-{day}-{year} in the workspace"))
+}
+
+fn list(year: Option<u16>) -> Result<(), String> {
+    for day in registry::DAYS
+        .iter()
+        .filter(|d| year.is_none_or(|y| d.year == y))
+    {
+        let parts: Vec<_> = (1..=2)
+            .map(|part| {
+                if day.has_part(part) {
+                    format!("part{part}")
+                } else {
+                    "-".to_owned()
+                }
+            })
+            .collect();
+        let status = if day.is_stub() { " (stub)" } else { "" };
+        println!("{:<12} {}{status}", day.package(), parts.join(" "));
+    }
+    Ok(())
+}
+
+/// Runs the whole year, panics included, and prints a table for `readme.md` or JSON.
+fn summary(year: u16, json: bool) -> Result<(), String> {
+    let days: Vec<_> = registry::year(year).collect();
+    if days.is_empty() {
+        return Err(format!("no days for {year} in the workspace"));
+    }
+
+    let summary = Summary::run(year, &days);
+    if json {
+        println!("{}", summary.to_json());
+    } else {
+        print!("{}", summary.to_markdown());
+    }
+
+    let broken = summary.count(Status::Failed) + summary.count(Status::Panicked);
+    if broken > 0 {
+        return Err(format!("{broken} parts failed or panicked"));
+    }
+    Ok(())
+}
+
+fn new_day(year: u16, day: u8) -> Result<(), String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc is inside the workspace");
+    let dir = scaffold::create(root, year, day)?;
+    println!("created {}", dir.display());
+    println!("registered as a stub, add its parts in aoc/src/registry.rs once they're solved");
+    Ok(())
+}
+
+fn run_year(year: u16) -> Result<(), String> {
+    let days: Vec<_> = registry::year(year).collect();
+    if days.is_empty() {
+        return Err(format!("no days for {year} in the workspace"));
+    }
+
+    for day in &days {
+        run_day(day, &[1, 2], None)?;
+    }
+
+    let stubs: Vec<_> = days
+        .iter()
+        .filter(|d| d.is_stub())
+        .map(|d| d.day.to_string())
+        .collect();
+    if !stubs.is_empty() {
+        warn!("stubs: {}", stubs.join(", "));
+    }
+    Ok(())
+}
+
+fn run_day(day: &Day, parts: &[u8], input: Option<&str>) -> Result<(), String> {
+    if day.is_stub() {
+        warn!("{} day {}: stub", day.year, day.day);
+        return Ok(());
+    }
+
+    if let Some(part) = parts.iter().find(|part| !(1..=2).contains(*part)) {
+        return Err(format!("part must be 1 or 2, got {part}"));
+    }
+
+    // both parts share the parsed input
+    let input = day.load_input(input).map_err(|err| err.to_string())?;
+    let failed = |err: AocError| format!("{} day {}: {err}", day.year, day.day);
+    let input = day.puzzle.parse(&input).map_err(failed)?;
+    for &part in parts {
+        if day.has_part(part) {
+            let now = std::time::Instant::now();
+            let output = day.puzzle.part(part, input.as_ref()).map_err(failed)?;
+            if quiet() {
+                println!("{output}");
+            } else {
+                println!(
+                    "{} day {} part {part}: {output} ({:?})",
+                    day.year,
+                    day.day,
+                    now.elapsed()
+                );
+            }
+        } else {
+            warn!("{} day {} part {part}: not implemented", day.year, day.day);
+        }
+    }
+    Ok(())
+}
+
+/// Runs every solved part and checks it against `<year>/answers.toml`.
+fn verify(year: Option<u16>, day: Option<u8>, input: Option<&str>) -> Result<(), String> {
+    let days: Vec<_> = registry::DAYS
+        .iter()
+        .filter(|d| year.is_none_or(|y| d.year == y) && day.is_none_or(|n| d.day == n))
+        .filter(|d| !d.is_stub())
+        .collect();
+    if days.is_empty() {
+        return Err("nothing to verify".to_owned());
+    }
+
+    let mut answers = BTreeMap::new();
+    let (mut pass, mut fail, mut unknown) = (0, 0, 0);
+
+    for day in days {
+        let answers = match answers.entry(day.year) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(Answers::load(day.year)?),
+        };
+
+        let raw = match day.load_input(input) {
+            Ok(raw) => raw,
+            Err(err) => {
+                println!("{} day {}: {err}", day.year, day.day);
+                unknown += day.parts.len();
+                continue;
+            }
+        };
+        let hash = input_hash(&raw);
+        let parsed = match day.puzzle.parse(&raw) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                println!("{} day {}: FAIL\n{err}", day.year, day.day);
+                fail += day.parts.len();
+                continue;
+            }
+        };
+
+        for &part in day.parts {
+            let label = format!("{} day {} part {part}", day.year, day.day);
+            let output = match day.puzzle.part(part, parsed.as_ref()) {
+                Ok(output) => output,
+                Err(err) => {
+                    fail += 1;
+                    println!("{label}: FAIL\n{err}");
+                    continue;
+                }
+            };
+            match Verdict::check(answers.expected(day.day, part, &hash), &output) {
+                Verdict::Pass => {
+                    pass += 1;
+                    println!("{label}: pass ({output})");
+                }
+                Verdict::Fail { expected } => {
+                    fail += 1;
+                    println!("{label}: FAIL (got {output}, expected {expected})");
+                }
+                Verdict::Unknown => {
+                    unknown += 1;
+                    println!("{label}: unknown ({output}, input {hash})");
+                }
+            }
+        }
+    }
+
+    println!("{pass} passed, {fail} failed, {unknown} unknown");
+    if fail > 0 {
+        return Err(format!("{fail} answers no longer match"));
+    }
+    Ok(())
+}
+
+fn bench_command(args: &mut Vec<&str>, input: Option<&str>) -> Result<(), String> {
+    let mut options = bench::Options::default();
+    if let Some(reps) = take_option(args, "--reps")? {
+        options.reps = parse(reps, "--reps")?;
+    }
+    if let Some(budget) = take_option(args, "--budget")? {
+        options.budget = Duration::from_secs_f64(parse(budget, "--budget")?);
+    }
+    let save = take_option(args, "--save")?;
+    let baseline = take_option(args, "--baseline")?
+        .map(|path| Report::load(Path::new(path)))
+        .transpose()?;
+
+    let (year, day) = match args.as_slice() {
+        ["bench"] => (None, None),
+        ["bench", year] => (Some(parse(year, "year")?), None),
+        ["bench", year, day] => (Some(parse(year, "year")?), Some(parse(day, "day")?)),
+        _ => return Err(USAGE.to_owned()),
+    };
+    if input.is_some() && day.is_none() {
+        return Err(single_day_input());
+    }
+
+    let report = run_bench(year, day, input, options, baseline.as_ref())?;
+    if let Some(path) = save {
+        report.save(Path::new(path))?;
+        println!("saved to {path}");
+    }
+
+    let regressions = baseline.map_or(0, |baseline| {
+        report
+            .records
+            .iter()
+            .filter(|record| {
+                baseline
+                    .change(record)
+                    .is_some_and(|c| c > REGRESSION_THRESHOLD)
+            })
+            .count()
+    });
+    if regressions > 0 {
+        return Err(format!("{regressions} timings regressed"));
+    }
+    Ok(())
+}
+
+/// Times every solved day, see `aoc::bench` for how samples are taken.
+fn run_bench(
+    year: Option<u16>,
+    day: Option<u8>,
+    input: Option<&str>,
+    options: bench::Options,
+    baseline: Option<&Report>,
+) -> Result<Report, String> {
+    let days: Vec<_> = registry::DAYS
+        .iter()
+        .filter(|d| year.is_none_or(|y| d.year == y) && day.is_none_or(|n| d.day == n))
+        .filter(|d| !d.is_stub())
+        .collect();
+    if days.is_empty() {
+        return Err("nothing to bench".to_owned());
+    }
+
+    let mut report = Report::default();
+    for day in days {
+        let input = day.load_input(input).map_err(|err| err.to_string())?;
+        let records = bench::day(day, &input, options)
+            .map_err(|err| format!("{} day {}: {err}", day.year, day.day))?;
+        for record in records {
+            let change = match baseline.and_then(|baseline| baseline.change(&record)) {
+                Some(change) if change > REGRESSION_THRESHOLD => {
+                    format!("  REGRESSION {:+.0}%", change * 100.0)
+                }
+                Some(change) => format!("  {:+.0}%", change * 100.0),
+                None if baseline.is_some() => "  new".to_owned(),
+                None => String::new(),
+            };
+            println!("{record}{change}");
+            report.records.push(record);
+        }
+    }
+    Ok(report)
+}
@@ -0,0 +1,167 @@
+use std::{any::Any, marker::PhantomData, path::Path};
+
+use common::{
+    input::{self, InputError},
+    solution::Params,
+    AocError, Solution,
+};
+
+/// A [`Solution`] with its input type erased, so days with different inputs fit in one table.
+pub trait Puzzle: Sync {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, AocError>;
+    fn part_with(&self, part: u8, input: &dyn Any, params: &Params) -> Result<String, AocError>;
+
+    fn part(&self, part: u8, input: &dyn Any) -> Result<String, AocError> {
+        self.part_with(part, input, &Params::new())
+    }
+}
+
+pub struct Erased<S>(PhantomData<fn() -> S>);
+
+impl<S> Puzzle for Erased<S>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, AocError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn part_with(&self, part: u8, input: &dyn Any, params: &Params) -> Result<String, AocError> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input should come from the same puzzle");
+        match part {
+            1 => S::part1_with(input, params).map(|output| output.to_string()),
+            2 => S::part2_with(input, params).map(|output| output.to_string()),
+            _ => panic!("there is no part {part}"),
+        }
+    }
+}
+
+/// A workspace member, e.g. `2023/day-17-2023`.
+///
+/// Days that are still `todo!()` stubs have no parts registered.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub input_path: &'static str,
+    pub puzzle: &'static dyn Puzzle,
+    pub parts: &'static [u8],
+}
+
+impl Day {
+    pub fn package(&self) -> String {
+        format!("day-{}-{}", self.day, self.year)
+    }
+
+    pub fn has_part(&self, part: u8) -> bool {
+        self.parts.contains(&part)
+    }
+
+    pub fn is_stub(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    /// The day's crate directory, e.g. `2023/day-17-2023`.
+    pub fn dir(&self) -> &'static Path {
+        Path::new(self.input_path)
+            .parent()
+            .expect("input path should be inside the day")
+    }
+
+    /// Reads the day's input, `arg` overrides it with a path or `-` for stdin.
+    pub fn load_input(&self, arg: Option<&str>) -> Result<String, InputError> {
+        input::load(arg, self.year, self.day, self.input_path)
+    }
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $krate:ident :: $solution:ident, [$($part:literal),*]) => {
+        Day {
+            year: $year,
+            day: $day,
+            input_path: $krate::INPUT_PATH,
+            puzzle: &Erased::<$krate::$solution>(PhantomData),
+            parts: &[$($part),*],
+        }
+    };
+}
+
+#[rustfmt::skip]
+pub static DAYS: &[Day] = &[
+    day!(2015, 1, day_1_2015::Day1, [1, 2]),
+    day!(2015, 2, day_2_2015::Day2, [1, 2]),
+    day!(2015, 3, day_3_2015::Day3, [1, 2]),
+    day!(2022, 1, day_1_2022::Day1, [1, 2]),
+    day!(2022, 2, day_2_2022::Day2, [1, 2]),
+    day!(2023, 1, day_1_2023::Day1, [1, 2]),
+    day!(2023, 2, day_2_2023::Day2, [1, 2]),
+    day!(2023, 3, day_3_2023::Day3, [1, 2]),
+    day!(2023, 4, day_4_2023::Day4, [1, 2]),
+    day!(2023, 5, day_5_2023::Day5, [1, 2]),
+    day!(2023, 6, day_6_2023::Day6, [1, 2]),
+    day!(2023, 7, day_7_2023::Day7, [1, 2]),
+    day!(2023, 8, day_8_2023::Day8, [1, 2]),
+    day!(2023, 9, day_9_2023::Day9, [1, 2]),
+    day!(2023, 10, day_10_2023::Day10, [1, 2]),
+    day!(2023, 11, day_11_2023::Day11, [1, 2]),
+    day!(2023, 12, day_12_2023::Day12, [1, 2]),
+    day!(2023, 13, day_13_2023::Day13, [1, 2]),
+    day!(2023, 14, day_14_2023::Day14, [1, 2]),
+    day!(2023, 15, day_15_2023::Day15, [1, 2]),
+    day!(2023, 16, day_16_2023::Day16, [1, 2]),
+    day!(2023, 17, day_17_2023::Day17, [1, 2]),
+    day!(2023, 18, day_18_2023::Day18, [1, 2]),
+    day!(2023, 19, day_19_2023::Day19, [1]),
+    day!(2023, 20, day_20_2023::Day20, []),
+    day!(2023, 21, day_21_2023::Day21, []),
+    day!(2023, 22, day_22_2023::Day22, []),
+    day!(2023, 23, day_23_2023::Day23, []),
+    day!(2023, 24, day_24_2023::Day24, []),
+    day!(2023, 25, day_25_2023::Day25, []),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+pub fn year(year: u16) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |d| d.year == year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{collections::BTreeSet, fs, path::Path};
+
+    #[test]
+    fn registry_matches_workspace_members() {
+        // Every `*/day-*` directory in the workspace should be registered exactly once.
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let mut members = BTreeSet::new();
+        for year in fs::read_dir(root).unwrap().flatten() {
+            if !year.path().is_dir() {
+                continue;
+            }
+            for day in fs::read_dir(year.path()).unwrap().flatten() {
+                let name = day.file_name().to_string_lossy().to_string();
+                if name.starts_with("day-") {
+                    members.insert(name);
+                }
+            }
+        }
+
+        let registered: BTreeSet<_> = DAYS.iter().map(Day::package).collect();
+        assert_eq!(registered.len(), DAYS.len());
+        assert_eq!(registered, members);
+    }
+
+    #[test]
+    fn stubs() {
+        let stubs: Vec<_> = year(2023).filter(|d| d.is_stub()).map(|d| d.day).collect();
+        assert_eq!(stubs, vec![20, 21, 22, 23, 24, 25]);
+        assert!(!find(2023, 19).unwrap().has_part(2));
+        assert!(find(2023, 19).unwrap().has_part(1));
+    }
+}
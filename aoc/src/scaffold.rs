@@ -0,0 +1,214 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB: &str = include_str!("../templates/lib.rs.tmpl");
+const PART: &str = include_str!("../templates/part.rs.tmpl");
+const BIN: &str = include_str!("../templates/bin.rs.tmpl");
+
+/// The files of a new `<year>/day-<day>-<year>` crate, relative to it.
+pub fn files(year: u16, day: u8) -> Vec<(PathBuf, String)> {
+    let fill = |template: &str, part: u8| {
+        template
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &day.to_string())
+            .replace("{part}", &part.to_string())
+    };
+
+    let mut files = vec![
+        ("Cargo.toml".into(), fill(CARGO_TOML, 0)),
+        ("input.txt".into(), String::new()),
+        ("src/lib.rs".into(), fill(LIB, 0)),
+    ];
+    for part in [1, 2] {
+        files.push((format!("src/part{part}.rs").into(), fill(PART, part)));
+        files.push((format!("src/bin/part{part}.rs").into(), fill(BIN, part)));
+    }
+    files
+}
+
+/// Creates a stub day in the workspace at `root` and registers it with the runner.
+///
+/// Refuses to touch a day that already exists, returns the new crate's directory.
+pub fn create(root: &Path, year: u16, day: u8) -> Result<PathBuf, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day must be 1 to 25, got {day}"));
+    }
+
+    let dir = root
+        .join(year.to_string())
+        .join(format!("day-{day}-{year}"));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    // read both before writing anything, so a failure leaves the workspace as it was
+    let manifest_path = root.join("aoc/Cargo.toml");
+    let registry_path = root.join("aoc/src/registry.rs");
+    let manifest = read(&manifest_path)?;
+    let registry = read(&registry_path)?;
+    let manifest = insert_sorted(
+        &manifest,
+        &format!("day-{day}-{year} = {{ path = \"../{year}/day-{day}-{year}\" }}"),
+        manifest_key,
+    )?;
+    let registry = insert_sorted(
+        &registry,
+        &format!("    day!({year}, {day}, day_{day}_{year}::Day{day}, []),"),
+        registry_key,
+    )?;
+
+    for (path, contents) in files(year, day) {
+        let path = dir.join(path);
+        let parent = path.parent().expect("files are inside the day");
+        fs::create_dir_all(parent).map_err(|err| format!("{}: {err}", parent.display()))?;
+        fs::write(&path, contents).map_err(|err| format!("{}: {err}", path.display()))?;
+    }
+    write(&manifest_path, &manifest)?;
+    write(&registry_path, &registry)?;
+    Ok(dir)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// `day-17-2023 = { path = ... }` in `aoc/Cargo.toml`.
+fn manifest_key(line: &str) -> Option<(u16, u8)> {
+    let (day, rest) = line.strip_prefix("day-")?.split_once('-')?;
+    let (year, _) = rest.split_once(' ')?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// `day!(2023, 17, day_17_2023::Day17, [1, 2]),` in `aoc/src/registry.rs`.
+fn registry_key(line: &str) -> Option<(u16, u8)> {
+    let args = line.trim_start().strip_prefix("day!(")?;
+    let mut args = args.split(',').map(str::trim);
+    Some((args.next()?.parse().ok()?, args.next()?.parse().ok()?))
+}
+
+/// Adds `line` among the lines `key` recognises, keeping them ordered by year then day.
+fn insert_sorted(
+    text: &str,
+    line: &str,
+    key: fn(&str) -> Option<(u16, u8)>,
+) -> Result<String, String> {
+    let new = key(line).expect("the new line has a key");
+    let mut lines: Vec<&str> = text.lines().collect();
+
+    let keyed: Vec<_> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, key(line)?)))
+        .collect();
+    if keyed.iter().any(|&(_, k)| k == new) {
+        return Err(format!("{line:?} is already there"));
+    }
+    let at = match keyed.iter().find(|&&(_, k)| k > new) {
+        Some(&(i, _)) => i,
+        None => keyed
+            .last()
+            .map(|&(i, _)| i + 1)
+            .ok_or("nowhere to add the day")?,
+    };
+
+    lines.insert(at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys() {
+        assert_eq!(
+            manifest_key(r#"day-17-2023 = { path = "../2023/day-17-2023" }"#),
+            Some((2023, 17))
+        );
+        assert_eq!(manifest_key(r#"common = { workspace = true }"#), None);
+        assert_eq!(
+            registry_key("    day!(2015, 3, day_3_2015::Day3, [1, 2]),"),
+            Some((2015, 3))
+        );
+        assert_eq!(registry_key("macro_rules! day {"), None);
+    }
+
+    #[test]
+    fn insert() {
+        let text = "a\n    day!(2015, 3, x, []),\n    day!(2023, 1, x, []),\nb\n";
+        let line = "    day!(2022, 1, y, []),";
+        assert_eq!(
+            insert_sorted(text, line, registry_key).unwrap(),
+            "a\n    day!(2015, 3, x, []),\n    day!(2022, 1, y, []),\n    day!(2023, 1, x, []),\nb\n"
+        );
+        let line = "    day!(2024, 1, y, []),";
+        assert_eq!(
+            insert_sorted(text, line, registry_key).unwrap(),
+            "a\n    day!(2015, 3, x, []),\n    day!(2023, 1, x, []),\n    day!(2024, 1, y, []),\nb\n"
+        );
+        assert!(insert_sorted(text, "    day!(2023, 1, y, []),", registry_key).is_err());
+    }
+
+    #[test]
+    fn templates() {
+        let files = files(2024, 7);
+        let file = |name: &str| {
+            &files
+                .iter()
+                .find(|(path, _)| path == Path::new(name))
+                .unwrap()
+                .1
+        };
+
+        assert!(file("Cargo.toml").contains("name = \"day-7-2024\""));
+        assert!(file("src/lib.rs").contains("pub struct Day7;"));
+        assert!(file("src/lib.rs").contains("const YEAR: u16 = 2024;"));
+        assert!(file("src/part2.rs").contains("fn day7_2024_part2()"));
+        assert!(file("src/bin/part2.rs").contains("run::<Day7>(2, INPUT_PATH)"));
+        assert!(file("input.txt").is_empty());
+        for (path, contents) in &files {
+            for placeholder in ["{year}", "{day}", "{part}"] {
+                assert!(!contents.contains(placeholder), "{placeholder} in {path:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn create_registers_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let aoc = Path::new(env!("CARGO_MANIFEST_DIR"));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::copy(aoc.join("Cargo.toml"), root.join("aoc/Cargo.toml")).unwrap();
+        fs::copy(
+            aoc.join("src/registry.rs"),
+            root.join("aoc/src/registry.rs"),
+        )
+        .unwrap();
+
+        let dir = create(&root, 2999, 1).unwrap();
+        assert!(dir.join("src/bin/part1.rs").exists());
+        assert!(read(&root.join("aoc/Cargo.toml"))
+            .unwrap()
+            .contains(r#"day-1-2999 = { path = "../2999/day-1-2999" }"#));
+        assert!(read(&root.join("aoc/src/registry.rs"))
+            .unwrap()
+            .contains("    day!(2999, 1, day_1_2999::Day1, []),\n];"));
+        assert!(create(&root, 2999, 1).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn refuses_to_overwrite() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let err = create(&root, 2023, 17).unwrap_err();
+        assert!(err.contains("already exists"));
+    }
+}
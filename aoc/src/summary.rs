@@ -0,0 +1,278 @@
+use std::{
+    any::Any,
+    fmt::Write,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Day;
+
+/// The message `todo!()` panics with, a part that still has it is a stub.
+const TODO: &str = "not yet implemented";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Solved,
+    /// Still `todo!()`, or a stub day without an input.
+    Stub,
+    /// The input didn't load or parse, or the part returned an error.
+    Failed,
+    Panicked,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Stub => "stub",
+            Status::Failed => "failed",
+            Status::Panicked => "panicked",
+        }
+    }
+}
+
+/// One part of one day, as written to JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    /// The part alone, parsing the shared input isn't counted.
+    pub runtime_ns: Option<u64>,
+    /// Why the part failed or panicked.
+    pub error: Option<String>,
+}
+
+impl Row {
+    fn new(day: &Day, part: u8, status: Status) -> Self {
+        Self {
+            day: day.day,
+            part,
+            status,
+            answer: None,
+            runtime_ns: None,
+            error: None,
+        }
+    }
+
+    fn error(day: &Day, part: u8, status: Status, error: impl ToString) -> Self {
+        Self {
+            error: Some(error.to_string()),
+            ..Self::new(day, part, status)
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Summary {
+    pub year: u16,
+    pub rows: Vec<Row>,
+}
+
+impl Summary {
+    /// Runs both parts of every day in `days`, a panic only takes down its own part.
+    pub fn run(year: u16, days: &[&Day]) -> Self {
+        // the panics are reported in the table, not on stderr
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let rows = days.iter().flat_map(|day| rows(day)).collect();
+        panic::set_hook(hook);
+
+        Self { year, rows }
+    }
+
+    pub fn count(&self, status: Status) -> usize {
+        self.rows.iter().filter(|row| row.status == status).count()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("summaries always serialize")
+    }
+
+    /// A table to paste into `readme.md`.
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::new();
+        md.push_str("| Day | Part | Answer | Runtime | Status |\n");
+        md.push_str("|----:|-----:|--------|--------:|--------|\n");
+        for row in &self.rows {
+            let answer = match (&row.answer, &row.error) {
+                (Some(answer), _) => format!("`{answer}`"),
+                (None, Some(error)) => cell(error),
+                (None, None) => String::new(),
+            };
+            let runtime = row
+                .runtime_ns
+                .map(|ns| format!("{:.2?}", Duration::from_nanos(ns)))
+                .unwrap_or_default();
+            writeln!(
+                md,
+                "| {} | {} | {answer} | {runtime} | {} |",
+                row.day,
+                row.part,
+                row.status.as_str()
+            )
+            .expect("writing to a string can't fail");
+        }
+        md
+    }
+}
+
+/// The first line of `text`, safe inside a table cell.
+fn cell(text: &str) -> String {
+    text.lines().next().unwrap_or_default().replace('|', "\\|")
+}
+
+fn rows(day: &Day) -> Vec<Row> {
+    let failed = |error: String| -> Vec<Row> {
+        [1, 2]
+            .map(|part| Row::error(day, part, Status::Failed, &error))
+            .into()
+    };
+
+    let input = match day.load_input(None) {
+        Ok(input) => input,
+        Err(_) if day.is_stub() => {
+            return [1, 2].map(|part| Row::new(day, part, Status::Stub)).into()
+        }
+        Err(err) => return failed(err.to_string()),
+    };
+    let parsed = match catch(|| day.puzzle.parse(&input)) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(err)) => return failed(err.to_string()),
+        Err(panic) => return [1, 2].map(|part| panic.row(day, part)).into(),
+    };
+
+    [1, 2]
+        .map(|part| {
+            let now = Instant::now();
+            let output = catch(|| day.puzzle.part(part, parsed.as_ref()));
+            let runtime = now.elapsed();
+            match output {
+                Ok(Ok(answer)) => Row {
+                    answer: Some(answer),
+                    runtime_ns: Some(runtime.as_nanos().try_into().unwrap_or(u64::MAX)),
+                    ..Row::new(day, part, Status::Solved)
+                },
+                Ok(Err(err)) => Row::error(day, part, Status::Failed, err),
+                Err(panic) => panic.row(day, part),
+            }
+        })
+        .into()
+}
+
+struct Panic {
+    message: String,
+}
+
+impl Panic {
+    fn row(&self, day: &Day, part: u8) -> Row {
+        if self.message.starts_with(TODO) {
+            Row::new(day, part, Status::Stub)
+        } else {
+            Row::error(day, part, Status::Panicked, &self.message)
+        }
+    }
+}
+
+fn catch<T>(f: impl FnOnce() -> T) -> Result<T, Panic> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload: Box<dyn Any + Send>| {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "panicked".to_owned()
+        };
+        Panic { message }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{registry, Puzzle};
+    use common::{solution::Params, AocError};
+
+    struct Panics;
+
+    impl Puzzle for Panics {
+        fn parse(&self, _input: &str) -> Result<Box<dyn Any>, AocError> {
+            Ok(Box::new(()))
+        }
+
+        fn part_with(&self, part: u8, _: &dyn Any, _: &Params) -> Result<String, AocError> {
+            match part {
+                1 => panic!("index out of bounds"),
+                _ => Err(AocError::invalid("no start")),
+            }
+        }
+    }
+
+    #[test]
+    fn catches_panics() {
+        let day = Day {
+            year: 2999,
+            day: 1,
+            input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"),
+            puzzle: &Panics,
+            parts: &[1, 2],
+        };
+        let stub = registry::find(2023, 20).unwrap();
+        let summary = Summary::run(2999, &[&day, stub]);
+
+        let statuses: Vec<_> = summary
+            .rows
+            .iter()
+            .map(|r| (r.day, r.part, r.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            [
+                (1, 1, Status::Panicked),
+                (1, 2, Status::Failed),
+                (20, 1, Status::Stub),
+                (20, 2, Status::Stub),
+            ]
+        );
+        assert_eq!(
+            summary.rows[0].error.as_deref(),
+            Some("index out of bounds")
+        );
+        assert_eq!(summary.count(Status::Stub), 2);
+    }
+
+    #[test]
+    fn markdown() {
+        let summary = Summary {
+            year: 2023,
+            rows: vec![
+                Row {
+                    answer: Some("1260".to_owned()),
+                    runtime_ns: Some(1_500_000),
+                    ..Row::new(registry::find(2023, 17).unwrap(), 1, Status::Solved)
+                },
+                Row::error(
+                    registry::find(2023, 17).unwrap(),
+                    2,
+                    Status::Failed,
+                    "parse error: a | b\n  |",
+                ),
+            ],
+        };
+        assert_eq!(
+            summary.to_markdown(),
+            "| Day | Part | Answer | Runtime | Status |\n\
+             |----:|-----:|--------|--------:|--------|\n\
+             | 17 | 1 | `1260` | 1.50ms | solved |\n\
+             | 17 | 2 | parse error: a \\| b |  | failed |\n"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&summary.to_json()).unwrap();
+        assert_eq!(json["rows"][0]["status"], "solved");
+        assert_eq!(json["rows"][1]["answer"], serde_json::Value::Null);
+    }
+}
@@ -0,0 +1,57 @@
+//! Runs every day's `examples/<name>.txt` against the answers in `examples/<name>.toml`.
+//!
+//! Tests are named `<year>::day-<day>::<name>::part<part>`, so
+//! `cargo test -p aoc --test examples -- 2023::day-11::` runs a single day.
+
+use aoc::{examples::Example, Day, DAYS};
+use libtest_mimic::{Arguments, Failed, Trial};
+
+fn main() {
+    let args = Arguments::from_args();
+
+    let mut trials = Vec::new();
+    for day in DAYS {
+        let prefix = format!("{}::day-{}", day.year, day.day);
+        let examples = match day.examples() {
+            Ok(examples) => examples,
+            Err(err) => {
+                trials.push(Trial::test(format!("{prefix}::examples"), move || {
+                    Err(err.into())
+                }));
+                continue;
+            }
+        };
+
+        for example in examples {
+            let example = std::sync::Arc::new(example);
+            for part in [1, 2] {
+                if example.expected(part).is_none() {
+                    continue;
+                }
+                let name = format!("{prefix}::{}::part{part}", example.name);
+                let example = example.clone();
+                trials.push(Trial::test(name, move || check(day, &example, part)));
+            }
+        }
+    }
+
+    libtest_mimic::run(&args, trials).exit();
+}
+
+fn check(day: &Day, example: &Example, part: u8) -> Result<(), Failed> {
+    if !day.has_part(part) {
+        return Err(format!("{} has no part {part} yet", day.package()).into());
+    }
+
+    let input = day.puzzle.parse(&example.input)?;
+    let output = day
+        .puzzle
+        .part_with(part, input.as_ref(), &example.params)?;
+    let expected = example
+        .expected(part)
+        .expect("only expected parts are checked");
+    if output != expected {
+        return Err(format!("expected {expected}, got {output}").into());
+    }
+    Ok(())
+}
@@ -0,0 +1,258 @@
+use std::{
+    fmt::Display,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign},
+};
+
+use crate::Matrix;
+
+/// A grid of booleans with each row packed into `u64` words, so whole rows compare and count
+/// a word at a time.
+///
+/// Bits past the last column are always clear.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    nrows: usize,
+    ncols: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// All clear.
+    pub fn new(nrows: usize, ncols: usize) -> Self {
+        let words_per_row = ncols.div_ceil(64);
+        Self {
+            nrows,
+            ncols,
+            words_per_row,
+            words: vec![0; nrows * words_per_row],
+        }
+    }
+
+    /// Set where `predicate` holds for the matrix's element.
+    pub fn from_matrix<T>(matrix: &Matrix<T>, predicate: impl Fn(&T) -> bool) -> Self {
+        let mut grid = Self::new(matrix.nrows(), matrix.ncols());
+        for (pos, element) in matrix.indexed_iter() {
+            if predicate(element) {
+                grid.set(pos.y, pos.x, true);
+            }
+        }
+        grid
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// The word and the bit in it, panics outside the grid.
+    fn locate(&self, row: usize, col: usize) -> (usize, u64) {
+        assert!(
+            row < self.nrows && col < self.ncols,
+            "({row}, {col}) is outside the {}x{} grid",
+            self.nrows,
+            self.ncols
+        );
+        (row * self.words_per_row + col / 64, 1 << (col % 64))
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> bool {
+        let (word, bit) = self.locate(row, col);
+        self.words[word] & bit != 0
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: bool) {
+        let (word, bit) = self.locate(row, col);
+        if value {
+            self.words[word] |= bit;
+        } else {
+            self.words[word] &= !bit;
+        }
+    }
+
+    /// Flips the bit and returns its new value.
+    pub fn toggle(&mut self, row: usize, col: usize) -> bool {
+        let (word, bit) = self.locate(row, col);
+        self.words[word] ^= bit;
+        self.words[word] & bit != 0
+    }
+
+    /// The words of `row`, column 0 is the lowest bit of the first.
+    pub fn row(&self, row: usize) -> &[u64] {
+        assert!(row < self.nrows, "row {row} of {}", self.nrows);
+        let start = row * self.words_per_row;
+        &self.words[start..start + self.words_per_row]
+    }
+
+    /// How many bits are set in the whole grid.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn row_count_ones(&self, row: usize) -> usize {
+        self.row(row)
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn col_count_ones(&self, col: usize) -> usize {
+        (0..self.nrows).filter(|&row| self.get(row, col)).count()
+    }
+
+    pub fn rows_equal(&self, a: usize, b: usize) -> bool {
+        self.row(a) == self.row(b)
+    }
+
+    /// How many columns rows `a` and `b` differ in.
+    pub fn row_distance(&self, a: usize, b: usize) -> usize {
+        (self.row(a).iter().zip(self.row(b)))
+            .map(|(a, b)| (a ^ b).count_ones() as usize)
+            .sum()
+    }
+
+    /// Rows become columns, so column checks can use the row ones.
+    pub fn transposed(&self) -> Self {
+        let mut grid = Self::new(self.ncols, self.nrows);
+        for row in 0..self.nrows {
+            for col in 0..self.ncols {
+                if self.get(row, col) {
+                    grid.set(col, row, true);
+                }
+            }
+        }
+        grid
+    }
+
+    fn zip_with(&mut self, other: &BitGrid, f: impl Fn(&mut u64, u64)) {
+        assert_eq!(
+            (self.nrows, self.ncols),
+            (other.nrows, other.ncols),
+            "grids must be the same shape"
+        );
+        for (word, &other) in self.words.iter_mut().zip(&other.words) {
+            f(word, other);
+        }
+    }
+}
+
+// Bulk operations need grids of the same shape and panic otherwise.
+
+impl BitAndAssign<&BitGrid> for BitGrid {
+    fn bitand_assign(&mut self, rhs: &BitGrid) {
+        self.zip_with(rhs, |word, other| *word &= other);
+    }
+}
+
+impl BitOrAssign<&BitGrid> for BitGrid {
+    fn bitor_assign(&mut self, rhs: &BitGrid) {
+        self.zip_with(rhs, |word, other| *word |= other);
+    }
+}
+
+impl BitXorAssign<&BitGrid> for BitGrid {
+    fn bitxor_assign(&mut self, rhs: &BitGrid) {
+        self.zip_with(rhs, |word, other| *word ^= other);
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, rhs: &BitGrid) -> BitGrid {
+        let mut grid = self.clone();
+        grid &= rhs;
+        grid
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, rhs: &BitGrid) -> BitGrid {
+        let mut grid = self.clone();
+        grid |= rhs;
+        grid
+    }
+}
+
+impl BitXor for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitxor(self, rhs: &BitGrid) -> BitGrid {
+        let mut grid = self.clone();
+        grid ^= rhs;
+        grid
+    }
+}
+
+/// `#` for set and `.` for clear, one row per line.
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.nrows {
+            for col in 0..self.ncols {
+                write!(f, "{}", if self.get(row, col) { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(input: &str) -> BitGrid {
+        BitGrid::from_matrix(&Matrix::parse_grid(input, |c| c).unwrap(), |&c| c == '#')
+    }
+
+    #[test]
+    fn bits() {
+        // wide enough to need two words per row
+        let mut g = BitGrid::new(2, 70);
+        g.set(0, 3, true);
+        g.set(1, 69, true);
+        assert!(g.get(0, 3) && g.get(1, 69) && !g.get(0, 69));
+        assert!(!g.toggle(0, 3));
+        assert!(g.toggle(0, 65));
+        assert_eq!(g.row(0), [0, 1 << 1]);
+        assert_eq!((g.count_ones(), g.row_count_ones(1)), (2, 1));
+        assert_eq!(g.col_count_ones(69), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "(0, 70) is outside the 2x70 grid")]
+    fn checks_bounds() {
+        BitGrid::new(2, 70).get(0, 70);
+    }
+
+    #[test]
+    fn rows() {
+        let g = grid("#.#\n#.#\n.##\n");
+        assert!(g.rows_equal(0, 1));
+        assert!(!g.rows_equal(0, 2));
+        assert_eq!(g.row_distance(1, 2), 2);
+        assert_eq!(g.transposed(), grid("##.\n..#\n###\n"));
+        assert_eq!(g.to_string(), "#.#\n#.#\n.##\n");
+    }
+
+    #[test]
+    fn bulk() {
+        let a = grid("##.\n..#\n");
+        let b = grid("#.#\n.##\n");
+        assert_eq!(&a & &b, grid("#..\n..#\n"));
+        assert_eq!(&a | &b, grid("###\n.##\n"));
+        assert_eq!(&a ^ &b, grid(".##\n.#.\n"));
+
+        let mut c = a.clone();
+        c ^= &a;
+        assert_eq!(c.count_ones(), 0);
+    }
+}
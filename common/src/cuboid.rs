@@ -0,0 +1,240 @@
+use crate::Vec3;
+
+/// An axis-aligned box of grid cells, both corners included like puzzle inputs write them, so
+/// it's never empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid {
+    min: Vec3<i64>,
+    max: Vec3<i64>,
+}
+
+impl Cuboid {
+    /// Any two opposite corners, in either order.
+    pub fn from_corners(a: Vec3<i64>, b: Vec3<i64>) -> Self {
+        Self {
+            min: Vec3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+            max: Vec3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+        }
+    }
+
+    /// `size` cells along each axis from `min`, `None` unless all are at least 1.
+    pub fn with_size(min: Vec3<i64>, size: Vec3<i64>) -> Option<Self> {
+        (size.x > 0 && size.y > 0 && size.z > 0).then(|| Self {
+            min,
+            max: min + size - Vec3::new(1, 1, 1),
+        })
+    }
+
+    pub fn min(&self) -> Vec3<i64> {
+        self.min
+    }
+
+    /// The far corner, inside the cuboid.
+    pub fn max(&self) -> Vec3<i64> {
+        self.max
+    }
+
+    /// Cells along each axis.
+    pub fn size(&self) -> Vec3<i64> {
+        self.max - self.min + Vec3::new(1, 1, 1)
+    }
+
+    pub fn volume(&self) -> i64 {
+        let size = self.size();
+        size.x * size.y * size.z
+    }
+
+    /// Around the outside of the cells.
+    pub fn surface_area(&self) -> i64 {
+        let size = self.size();
+        2 * (size.x * size.y + size.y * size.z + size.z * size.x)
+    }
+
+    pub fn contains(&self, point: Vec3<i64>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+
+    /// Whether they share at least one cell.
+    pub fn intersects(&self, other: &Cuboid) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+            && self.min.z <= other.max.z
+            && other.min.z <= self.max.z
+    }
+
+    /// The cells in both, if there are any.
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        self.intersects(other).then(|| Cuboid {
+            min: Vec3::new(
+                self.min.x.max(other.min.x),
+                self.min.y.max(other.min.y),
+                self.min.z.max(other.min.z),
+            ),
+            max: Vec3::new(
+                self.max.x.min(other.max.x),
+                self.max.y.min(other.max.y),
+                self.max.z.min(other.max.z),
+            ),
+        })
+    }
+
+    /// Every cell, x changing fastest and z slowest.
+    pub fn cells(&self) -> impl Iterator<Item = Vec3<i64>> {
+        let Cuboid { min, max } = *self;
+        (min.z..=max.z).flat_map(move |z| {
+            (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Vec3::new(x, y, z)))
+        })
+    }
+
+    pub fn translate(&self, offset: Vec3<i64>) -> Cuboid {
+        Cuboid {
+            min: self.min + offset,
+            max: self.max + offset,
+        }
+    }
+
+    /// The cells not in `other`, as up to 6 cuboids that don't overlap.
+    pub fn subtract(&self, other: &Cuboid) -> Vec<Cuboid> {
+        let Some(cut) = self.intersection(other) else {
+            return vec![*self];
+        };
+        let mut pieces = Vec::new();
+        // whole slabs below and above the cut, then the rows either side of it within its z
+        // range, then what's left either side of it in x
+        if self.min.z < cut.min.z {
+            pieces.push(Cuboid::from_corners(
+                self.min,
+                Vec3::new(self.max.x, self.max.y, cut.min.z - 1),
+            ));
+        }
+        if cut.max.z < self.max.z {
+            pieces.push(Cuboid::from_corners(
+                Vec3::new(self.min.x, self.min.y, cut.max.z + 1),
+                self.max,
+            ));
+        }
+        if self.min.y < cut.min.y {
+            pieces.push(Cuboid::from_corners(
+                Vec3::new(self.min.x, self.min.y, cut.min.z),
+                Vec3::new(self.max.x, cut.min.y - 1, cut.max.z),
+            ));
+        }
+        if cut.max.y < self.max.y {
+            pieces.push(Cuboid::from_corners(
+                Vec3::new(self.min.x, cut.max.y + 1, cut.min.z),
+                Vec3::new(self.max.x, self.max.y, cut.max.z),
+            ));
+        }
+        if self.min.x < cut.min.x {
+            pieces.push(Cuboid::from_corners(
+                Vec3::new(self.min.x, cut.min.y, cut.min.z),
+                Vec3::new(cut.min.x - 1, cut.max.y, cut.max.z),
+            ));
+        }
+        if cut.max.x < self.max.x {
+            pieces.push(Cuboid::from_corners(
+                Vec3::new(cut.max.x + 1, cut.min.y, cut.min.z),
+                Vec3::new(self.max.x, cut.max.y, cut.max.z),
+            ));
+        }
+        pieces
+    }
+
+    /// How many cells are in at least one of the cuboids, overlaps counted once.
+    pub fn union_volume(cuboids: impl IntoIterator<Item = Cuboid>) -> i64 {
+        let mut disjoint: Vec<Cuboid> = Vec::new();
+        for cuboid in cuboids {
+            disjoint = disjoint.iter().flat_map(|d| d.subtract(&cuboid)).collect();
+            disjoint.push(cuboid);
+        }
+        disjoint.iter().map(Cuboid::volume).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cuboid_measures() {
+        // a 2x3x4 present
+        let c = Cuboid::with_size(Vec3::new(0, 0, 0), Vec3::new(2, 3, 4)).unwrap();
+        assert_eq!(c.max(), Vec3::new(1, 2, 3));
+        assert_eq!(
+            c,
+            Cuboid::from_corners(Vec3::new(1, 2, 3), Vec3::new(0, 0, 0))
+        );
+        assert_eq!((c.volume(), c.surface_area()), (24, 52));
+        assert_eq!(c.cells().count(), 24);
+        assert_eq!(c.cells().nth(2), Some(Vec3::new(0, 1, 0)));
+        assert!(c.contains(Vec3::new(1, 2, 3)));
+        assert!(!c.contains(Vec3::new(1, 3, 3)));
+        assert_eq!(
+            Cuboid::with_size(Vec3::new(0, 0, 0), Vec3::new(2, 0, 4)),
+            None
+        );
+
+        // a brick falling one step
+        let brick = Cuboid::from_corners(Vec3::new(1, 0, 2), Vec3::new(1, 2, 2));
+        let fallen = brick.translate(Vec3::new(0, 0, -1));
+        assert_eq!(fallen.min(), Vec3::new(1, 0, 1));
+        assert!(!brick.intersects(&fallen.translate(Vec3::new(1, 0, 0))));
+    }
+
+    #[test]
+    fn cuboid_intersection() {
+        let a = Cuboid::from_corners(Vec3::new(10, 10, 10), Vec3::new(12, 12, 12));
+        let b = Cuboid::from_corners(Vec3::new(11, 11, 11), Vec3::new(13, 13, 13));
+        let c = Cuboid::from_corners(Vec3::new(0, 11, 11), Vec3::new(9, 20, 20));
+        assert_eq!(
+            a.intersection(&b),
+            Some(Cuboid::from_corners(
+                Vec3::new(11, 11, 11),
+                Vec3::new(12, 12, 12)
+            ))
+        );
+        assert!(!a.intersects(&c));
+        assert_eq!(a.intersection(&c), None);
+    }
+
+    #[test]
+    fn cuboid_subtract() {
+        let a = Cuboid::from_corners(Vec3::new(0, 0, 0), Vec3::new(3, 3, 3));
+        let hole = Cuboid::from_corners(Vec3::new(1, 1, 1), Vec3::new(2, 2, 2));
+        let pieces = a.subtract(&hole);
+        assert_eq!(pieces.len(), 6);
+        for cell in a.cells() {
+            let covering = pieces.iter().filter(|p| p.contains(cell)).count();
+            assert_eq!(covering, usize::from(!hole.contains(cell)));
+        }
+        assert!(hole.subtract(&a).is_empty());
+    }
+
+    #[test]
+    fn cuboid_reactor() {
+        // switching cubes on and off, the small example from 2021 day 22
+        let steps = [
+            (true, (10, 10, 10), (12, 12, 12)),
+            (true, (11, 11, 11), (13, 13, 13)),
+            (false, (9, 9, 9), (11, 11, 11)),
+            (true, (10, 10, 10), (10, 10, 10)),
+        ];
+        let mut on: Vec<Cuboid> = Vec::new();
+        for (state, a, b) in steps {
+            let cuboid = Cuboid::from_corners(Vec3::new(a.0, a.1, a.2), Vec3::new(b.0, b.1, b.2));
+            on = on.iter().flat_map(|c| c.subtract(&cuboid)).collect();
+            if state {
+                on.push(cuboid);
+            }
+        }
+        assert_eq!(on.iter().map(Cuboid::volume).sum::<i64>(), 39);
+
+        let a = Cuboid::from_corners(Vec3::new(10, 10, 10), Vec3::new(12, 12, 12));
+        let b = Cuboid::from_corners(Vec3::new(11, 11, 11), Vec3::new(13, 13, 13));
+        assert_eq!(Cuboid::union_volume([a, b]), 27 + 27 - 8);
+    }
+}
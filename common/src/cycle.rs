@@ -0,0 +1,106 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// A sequence of states, from an initial one and a step, that ends up repeating itself.
+#[derive(Debug, Clone)]
+pub struct Cycle<S> {
+    /// The first step of the first state that comes back.
+    pub start: usize,
+    /// How many steps until it comes back, at least 1.
+    pub len: usize,
+    /// Every state up to the first repeat, `history[i]` after `i` steps.
+    history: Vec<S>,
+}
+
+impl<S: Hash + Eq + Clone> Cycle<S> {
+    /// Steps from `initial` until a state comes back.
+    ///
+    /// Every state is kept, so this never ends for a sequence that doesn't repeat.
+    pub fn detect(initial: S, mut step: impl FnMut(&S) -> S) -> Self {
+        let mut seen = HashMap::new();
+        let mut history = Vec::new();
+        let mut state = initial;
+        loop {
+            if let Some(&start) = seen.get(&state) {
+                return Self {
+                    start,
+                    len: history.len() - start,
+                    history,
+                };
+            }
+            let next = step(&state);
+            seen.insert(state.clone(), history.len());
+            history.push(state);
+            state = next;
+        }
+    }
+
+    /// The state after `n` steps.
+    pub fn state_at(&self, n: usize) -> &S {
+        if n < self.history.len() {
+            &self.history[n]
+        } else {
+            &self.history[self.start + (n - self.start) % self.len]
+        }
+    }
+}
+
+/// The state after `n` steps from `initial`, skipping the repeats once the states start to
+/// cycle. Stops early if step `n` comes before any repeat.
+pub fn state_at<S: Hash + Eq + Clone>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut steps = 0;
+    let cycle = Cycle::detect(initial, |state| {
+        steps += 1;
+        if steps > n {
+            // step `n` is already known, repeat the state to end the search
+            state.clone()
+        } else {
+            step(state)
+        }
+    });
+    cycle.state_at(n).clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 1 2 3 4 5 6 2 3 ...
+    fn step(&x: &u32) -> u32 {
+        if x == 6 {
+            2
+        } else {
+            x + 1
+        }
+    }
+
+    #[test]
+    fn detect() {
+        let cycle = Cycle::detect(0, step);
+        assert_eq!((cycle.start, cycle.len), (2, 5));
+        assert_eq!(*cycle.state_at(4), 4);
+        assert_eq!(*cycle.state_at(7), 2);
+        assert_eq!(*cycle.state_at(1_000_000_000), 2 + (1_000_000_000 - 2) % 5);
+
+        let fixed = Cycle::detect('x', |&c| c);
+        assert_eq!((fixed.start, fixed.len), (0, 1));
+        assert_eq!(*fixed.state_at(10), 'x');
+    }
+
+    #[test]
+    fn state_at_stops_early() {
+        let mut steps = 0;
+        let state = state_at(
+            0u64,
+            |&x| {
+                steps += 1;
+                x + 1
+            },
+            10,
+        );
+        assert_eq!((state, steps), (10, 10));
+        assert_eq!(
+            state_at(0, step, 1_000_000_000),
+            2 + (1_000_000_000 - 2) % 5
+        );
+    }
+}
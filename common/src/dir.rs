@@ -0,0 +1,303 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::Vec2;
+
+/// One of the four orthogonal directions, with y pointing down like [`Vec2::DOWN`].
+///
+/// Numbered clockwise from up, `dir as u8` or [`Dir4::index`] fits a `[T; 4]` state table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// One of the eight directions to the surrounding cells, y pointing down.
+///
+/// Numbered clockwise from up, `dir as u8` or [`Dir8::index`] fits a `[T; 8]` state table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir4 {
+    /// Clockwise from up.
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Panics unless `index` is below 4.
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir4::Up | Dir4::Down)
+    }
+
+    /// The unit step, same as `Vec2::from(dir)`.
+    pub fn offset(self) -> Vec2<isize> {
+        match self {
+            Dir4::Up => Vec2::UP,
+            Dir4::Right => Vec2::RIGHT,
+            Dir4::Down => Vec2::DOWN,
+            Dir4::Left => Vec2::LEFT,
+        }
+    }
+}
+
+impl Dir8 {
+    /// Clockwise from up.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Panics unless `index` is below 8.
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index]
+    }
+
+    /// 45 degrees anticlockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    /// 45 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// The unit step, diagonals move one along both axes.
+    pub fn offset(self) -> Vec2<isize> {
+        match self {
+            Dir8::Up => Vec2::UP,
+            Dir8::UpRight => Vec2::new(1, -1),
+            Dir8::Right => Vec2::RIGHT,
+            Dir8::DownRight => Vec2::new(1, 1),
+            Dir8::Down => Vec2::DOWN,
+            Dir8::DownLeft => Vec2::new(-1, 1),
+            Dir8::Left => Vec2::LEFT,
+            Dir8::UpLeft => Vec2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(value: Dir4) -> Self {
+        Dir8::ALL[value.index() * 2]
+    }
+}
+
+impl From<Dir4> for Vec2<isize> {
+    fn from(value: Dir4) -> Self {
+        value.offset()
+    }
+}
+
+impl From<Dir8> for Vec2<isize> {
+    fn from(value: Dir8) -> Self {
+        value.offset()
+    }
+}
+
+impl From<Dir4> for u8 {
+    fn from(value: Dir4) -> Self {
+        value as u8
+    }
+}
+
+impl From<Dir8> for u8 {
+    fn from(value: Dir8) -> Self {
+        value as u8
+    }
+}
+
+impl TryFrom<u8> for Dir4 {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        (Self::ALL.get(value as usize).copied()).ok_or_else(|| format!("no direction {value}"))
+    }
+}
+
+impl TryFrom<u8> for Dir8 {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        (Self::ALL.get(value as usize).copied()).ok_or_else(|| format!("no direction {value}"))
+    }
+}
+
+impl TryFrom<Vec2<isize>> for Dir4 {
+    type Error = String;
+
+    fn try_from(value: Vec2<isize>) -> Result<Self, Self::Error> {
+        (Self::ALL.into_iter().find(|dir| dir.offset() == value))
+            .ok_or_else(|| format!("'{value}' isn't an orthogonal unit step"))
+    }
+}
+
+impl TryFrom<Vec2<isize>> for Dir8 {
+    type Error = String;
+
+    fn try_from(value: Vec2<isize>) -> Result<Self, Self::Error> {
+        (Self::ALL.into_iter().find(|dir| dir.offset() == value))
+            .ok_or_else(|| format!("'{value}' isn't a unit step"))
+    }
+}
+
+/// `U`/`R`/`D`/`L`, `^`/`>`/`v`/`<` or `N`/`E`/`S`/`W`.
+impl TryFrom<char> for Dir4 {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'U' | '^' | 'N' => Ok(Dir4::Up),
+            'R' | '>' | 'E' => Ok(Dir4::Right),
+            'D' | 'v' | 'S' => Ok(Dir4::Down),
+            'L' | '<' | 'W' => Ok(Dir4::Left),
+            _ => Err(format!("expected a direction, got '{value}'")),
+        }
+    }
+}
+
+/// Any single char [`Dir4`] takes.
+impl FromStr for Dir4 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => c.try_into(),
+            _ => Err(format!("expected a direction, got '{s}'")),
+        }
+    }
+}
+
+/// What [`Dir4`] takes, or a compass point with the diagonals `NE`, `SE`, `SW` and `NW`.
+impl FromStr for Dir8 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Dir8::UpRight),
+            "SE" => Ok(Dir8::DownRight),
+            "SW" => Ok(Dir8::DownLeft),
+            "NW" => Ok(Dir8::UpLeft),
+            _ => s.parse::<Dir4>().map(Dir8::from),
+        }
+    }
+}
+
+/// As an arrow, `^`, `>`, `v` or `<`.
+impl Display for Dir4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let arrow = match self {
+            Dir4::Up => '^',
+            Dir4::Right => '>',
+            Dir4::Down => 'v',
+            Dir4::Left => '<',
+        };
+        write!(f, "{arrow}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Left.turn_right(), Dir4::Up);
+        assert_eq!(Dir4::Right.opposite(), Dir4::Left);
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_left().turn_left(), dir.opposite());
+            assert_eq!(dir.opposite().offset(), -dir.offset());
+            // the same as rotating the vector
+            let mut v = dir.offset();
+            v.rotate_right();
+            assert_eq!(v, dir.turn_right().offset());
+        }
+
+        assert_eq!(Dir8::Up.turn_left(), Dir8::UpLeft);
+        assert_eq!(Dir8::UpLeft.turn_right(), Dir8::Up);
+        assert_eq!(Dir8::DownRight.opposite(), Dir8::UpLeft);
+        assert_eq!(Dir8::ALL.iter().filter(|d| d.is_diagonal()).count(), 4);
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Vec2::from(Dir4::Left), Vec2::LEFT);
+        assert_eq!(Dir4::try_from(Vec2::DOWN), Ok(Dir4::Down));
+        assert!(Dir4::try_from(Vec2::ONE).is_err());
+        assert_eq!(Dir8::try_from(Vec2::ONE), Ok(Dir8::DownRight));
+        assert_eq!(Dir8::from(Dir4::Left), Dir8::Left);
+
+        for (i, dir) in Dir8::ALL.into_iter().enumerate() {
+            assert_eq!(u8::from(dir) as usize, i);
+            assert_eq!(Dir8::try_from(i as u8), Ok(dir));
+        }
+        assert_eq!(Dir4::from_index(2), Dir4::Down);
+        assert!(Dir4::try_from(4u8).is_err());
+    }
+
+    #[test]
+    fn parsing() {
+        for (dir, notations) in Dir4::ALL.into_iter().zip(["U^N", "R>E", "DvS", "L<W"]) {
+            for c in notations.chars() {
+                assert_eq!(Dir4::try_from(c), Ok(dir));
+            }
+        }
+        assert_eq!("v".parse::<Dir4>(), Ok(Dir4::Down));
+        assert!("UP".parse::<Dir4>().is_err());
+        assert!(Dir4::try_from('x').is_err());
+
+        assert_eq!("NW".parse::<Dir8>(), Ok(Dir8::UpLeft));
+        assert_eq!("E".parse::<Dir8>(), Ok(Dir8::Right));
+        assert_eq!(Dir4::Down.to_string(), "v");
+    }
+}
@@ -0,0 +1,194 @@
+use std::fmt::Display;
+
+use nom::{error::Error, IResult};
+
+/// Why a day couldn't solve its input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    /// The input doesn't parse, pointed at the offending spot.
+    Parse {
+        /// 1-based.
+        line: usize,
+        /// 1-based, in chars.
+        column: usize,
+        /// The whole offending line.
+        snippet: String,
+        message: String,
+    },
+    /// A nom parser gave up with `rest` left over, see [`AocError::locate`].
+    Unlocated { rest: String, message: String },
+    /// The input parses but doesn't make sense, e.g. a map without a start.
+    Invalid(String),
+}
+
+impl AocError {
+    /// A parse error at byte `offset` of `input`.
+    pub fn at(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        AocError::Parse {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// A parse error where `rest`, a slice of `input`, starts.
+    pub fn at_rest(input: &str, rest: &str, message: impl Into<String>) -> Self {
+        Self::at(input, offset_of(input, rest), message)
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        AocError::Invalid(message.into())
+    }
+
+    /// Points an error from nom at its line and column in the whole `input`.
+    pub fn locate(self, input: &str) -> Self {
+        match self {
+            AocError::Unlocated { rest, message } => {
+                Self::at(input, input.len().saturating_sub(rest.len()), message)
+            }
+            err => err,
+        }
+    }
+}
+
+/// Where `rest` starts in `input`, or the end of `input` if it isn't a slice of it.
+fn offset_of(input: &str, rest: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let at = rest.as_ptr() as usize;
+    if (start..=start + input.len()).contains(&at) {
+        at - start
+    } else {
+        input.len()
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Parse {
+                line,
+                column,
+                snippet,
+                message,
+            } => {
+                let gutter = line.to_string().len();
+                writeln!(f, "parse error at line {line}, column {column}: {message}")?;
+                writeln!(f, "{:gutter$} |", "")?;
+                writeln!(f, "{line} | {snippet}")?;
+                write!(f, "{:gutter$} | {:>column$}", "", "^")
+            }
+            AocError::Unlocated { rest, message } => {
+                let rest = rest.lines().next().unwrap_or_default();
+                write!(f, "parse error: {message} at '{rest}'")
+            }
+            AocError::Invalid(message) => write!(f, "invalid input: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+impl From<nom::Err<Error<&str>>> for AocError {
+    fn from(err: nom::Err<Error<&str>>) -> Self {
+        match err {
+            nom::Err::Incomplete(_) => AocError::Unlocated {
+                rest: String::new(),
+                message: "unexpected end of input".to_owned(),
+            },
+            nom::Err::Error(err) | nom::Err::Failure(err) => AocError::Unlocated {
+                rest: err.input.to_owned(),
+                message: format!("expected {}", err.code.description()),
+            },
+        }
+    }
+}
+
+/// Runs `parser` over the whole input, anything but whitespace left over is an error.
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<O, AocError> {
+    let (rest, output) = parser(input).map_err(|err| AocError::from(err).locate(input))?;
+    if !rest.trim().is_empty() {
+        let rest = rest.trim_start();
+        return Err(AocError::at_rest(input, rest, "unexpected input"));
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{character::complete, multi::separated_list1};
+
+    #[test]
+    fn at() {
+        let input = "467..114..\n...*......\n..35..633.";
+        let err = AocError::at(input, 14, "bad symbol");
+        assert_eq!(
+            err,
+            AocError::Parse {
+                line: 2,
+                column: 4,
+                snippet: "...*......".to_owned(),
+                message: "bad symbol".to_owned()
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 2, column 4: bad symbol\n  |\n2 | ...*......\n  |    ^"
+        );
+
+        let err = AocError::at(input, input.len(), "eof");
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 3,
+                column: 11,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn crlf() {
+        let err = AocError::at("ab\r\ncd\r\n", 5, "x");
+        assert!(
+            matches!(err, AocError::Parse { line: 2, column: 2, ref snippet, .. } if snippet == "cd")
+        );
+    }
+
+    #[test]
+    fn nom() {
+        let numbers = |input| separated_list1(complete::char(','), complete::u32)(input);
+
+        assert_eq!(parse_all("1,2,3\n", numbers), Ok(vec![1, 2, 3]));
+
+        let err = parse_all("1,2\nx", numbers).unwrap_err();
+        assert!(
+            matches!(err, AocError::Parse { line: 2, column: 1, ref message, .. } if message == "unexpected input"),
+            "{err:?}"
+        );
+
+        let err = parse_all("x", numbers).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                AocError::Parse {
+                    line: 1,
+                    column: 1,
+                    ..
+                }
+            ),
+            "{err:?}"
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use std::{
+    fmt::Display,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Root directory holding everyone's own inputs as `<year>/day-<day>.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Picks the input for a day, in order:
+    /// - an explicit path argument (`-` reads stdin)
+    /// - `<input_dir>/<year>/day-<day>.txt` when an input dir is set
+    /// - the day's own `input.txt`
+    pub fn resolve(
+        arg: Option<&str>,
+        input_dir: Option<&Path>,
+        year: u16,
+        day: u8,
+        default: &str,
+    ) -> Self {
+        match (arg, input_dir) {
+            (Some("-"), _) => Source::Stdin,
+            (Some(path), _) => Source::File(path.into()),
+            (None, Some(dir)) => {
+                Source::File(dir.join(year.to_string()).join(format!("day-{day}.txt")))
+            }
+            (None, None) => Source::File(default.into()),
+        }
+    }
+
+    /// Same as [`Source::resolve`], taking the input dir from `AOC_INPUT_DIR`.
+    pub fn from_env(arg: Option<&str>, year: u16, day: u8, default: &str) -> Self {
+        let input_dir = std::env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+        Self::resolve(arg, input_dir.as_deref(), year, day, default)
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Source::File(path) => std::fs::read_to_string(path).map_err(|err| InputError {
+                from: self.clone(),
+                err,
+            }),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|err| InputError {
+                        from: self.clone(),
+                        err,
+                    })?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub from: Source,
+    pub err: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.from, self.err.kind()) {
+            (Source::File(path), io::ErrorKind::NotFound) => write!(
+                f,
+                "missing puzzle input, expected {} (pass a path, `-` for stdin, or set {INPUT_DIR_VAR})",
+                path.display()
+            ),
+            (from, _) => write!(f, "could not read puzzle input from {from}: {}", self.err),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.err)
+    }
+}
+
+/// Reads a day's input, see [`Source::resolve`] for where it is looked up.
+pub fn load(arg: Option<&str>, year: u16, day: u8, default: &str) -> Result<String, InputError> {
+    Source::from_env(arg, year, day, default).read()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve() {
+        let dir = Path::new("inputs");
+        let default = "2023/day-17-2023/input.txt";
+
+        assert_eq!(
+            Source::resolve(Some("mine.txt"), Some(dir), 2023, 17, default),
+            Source::File("mine.txt".into())
+        );
+        assert_eq!(
+            Source::resolve(Some("-"), Some(dir), 2023, 17, default),
+            Source::Stdin
+        );
+        assert_eq!(
+            Source::resolve(None, Some(dir), 2023, 17, default),
+            Source::File("inputs/2023/day-17.txt".into())
+        );
+        assert_eq!(
+            Source::resolve(None, None, 2023, 17, default),
+            Source::File(default.into())
+        );
+    }
+
+    #[test]
+    fn missing_file_names_expected_path() {
+        let source = Source::resolve(None, Some(Path::new("no-such-dir")), 2023, 17, "");
+        let err = source.read().unwrap_err();
+        assert_eq!(err.err.kind(), io::ErrorKind::NotFound);
+        assert!(err.to_string().contains("no-such-dir/2023/day-17.txt"));
+    }
+}
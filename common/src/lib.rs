@@ -1,5 +1,26 @@
+pub mod bit_grid;
+pub mod cuboid;
+pub mod cycle;
+pub mod dir;
+pub mod error;
+pub mod input;
+pub mod logging;
 pub mod matrix;
+pub mod polygon;
+pub mod rect;
+pub mod render;
+pub mod solution;
+pub mod sparse_grid;
 pub mod vec2;
+pub mod vec3;
 
+pub use bit_grid::BitGrid;
+pub use cuboid::Cuboid;
+pub use dir::{Dir4, Dir8};
+pub use error::AocError;
 pub use matrix::Matrix;
+pub use rect::Rect;
+pub use solution::Solution;
+pub use sparse_grid::SparseGrid;
 pub use vec2::Vec2;
+pub use vec3::Vec3;
@@ -0,0 +1,49 @@
+use log::LevelFilter;
+
+/// Takes `-q` and `-v`, `-vv`, `-vvv` out of the arguments.
+///
+/// Solutions log at debug and trace, so by default only warnings show up next to the
+/// answers, `-q` hides those too.
+pub fn verbosity(args: &mut Vec<String>) -> LevelFilter {
+    let mut level = LevelFilter::Warn;
+    args.retain(|arg| {
+        level = match arg.as_str() {
+            "-q" | "--quiet" => LevelFilter::Off,
+            "-v" => LevelFilter::Info,
+            "-vv" => LevelFilter::Debug,
+            "-vvv" => LevelFilter::Trace,
+            _ => return true,
+        };
+        false
+    });
+    level
+}
+
+/// Logs to stderr, so stdout only has the answers. `RUST_LOG` overrides `level`.
+pub fn init(level: LevelFilter) {
+    // already set up, e.g. by a test that runs a day's main
+    let _ = simple_logger::SimpleLogger::new()
+        .with_level(level)
+        .env()
+        .init();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verbosity_flags() {
+        let mut args = vec!["input.txt".to_owned(), "-vv".to_owned()];
+        assert_eq!(verbosity(&mut args), LevelFilter::Debug);
+        assert_eq!(args, vec!["input.txt"]);
+
+        let mut args = vec!["-".to_owned()];
+        assert_eq!(verbosity(&mut args), LevelFilter::Warn);
+        assert_eq!(args, vec!["-"]);
+
+        let mut args = vec!["-q".to_owned()];
+        assert_eq!(verbosity(&mut args), LevelFilter::Off);
+        assert!(args.is_empty());
+    }
+}
@@ -1,6 +1,34 @@
-use std::{fmt::Display, slice::Iter, usize};
+use std::{
+    convert::Infallible,
+    fmt::Display,
+    ops::{Index, IndexMut},
+    slice::{Iter, IterMut},
+};
 
-#[derive(PartialEq, Debug)]
+use crate::{AocError, Vec2};
+
+mod view;
+mod window;
+
+pub use view::{ColView, ColViewMut, RowView, RowViewMut};
+pub use window::Window;
+
+/// Up, right, down, left.
+pub(crate) const ORTHOGONAL: [Vec2<isize>; 4] = [Vec2::UP, Vec2::RIGHT, Vec2::DOWN, Vec2::LEFT];
+
+/// The 8 surrounding cells in reading order.
+pub(crate) const ADJACENT: [Vec2<isize>; 8] = [
+    Vec2 { x: -1, y: -1 },
+    Vec2::UP,
+    Vec2 { x: 1, y: -1 },
+    Vec2::LEFT,
+    Vec2::RIGHT,
+    Vec2 { x: -1, y: 1 },
+    Vec2::DOWN,
+    Vec2 { x: 1, y: 1 },
+];
+
+#[derive(PartialEq, Eq, Hash, Debug)]
 pub struct Matrix<T> {
     nrows: usize,
     ncols: usize,
@@ -9,18 +37,8 @@ pub struct Matrix<T> {
 
 impl<T: Display> Display for Matrix<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in 0..self.nrows {
-            write!(f, "[")?;
-            for col in 0..self.ncols {
-                let value = &self.data[row * self.ncols + col];
-                write!(f, "{}", value)?;
-                if col < self.ncols - 1 {
-                    write!(f, " ")?;
-                }
-            }
-            writeln!(f, "]")?;
-        }
-        Ok(())
+        // see `render` for other ways to draw a matrix
+        self.render().fmt(f)
     }
 }
 
@@ -33,64 +51,124 @@ impl<T> Matrix<T> {
         self.ncols
     }
 
-    pub fn iter(&self) -> Iter<T> {
+    /// Row by row.
+    pub fn iter(&self) -> Iter<'_, T> {
         self.data.iter()
     }
 
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// Row by row, with each element's position as `x` the column and `y` the row.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Vec2<usize>, &T)> {
+        let ncols = self.ncols;
+        (self.data.iter().enumerate())
+            .map(move |(i, element)| (Vec2::new(i % ncols, i / ncols), element))
+    }
+
+    /// A matrix of the same shape with `f` applied to every element.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Matrix<U> {
+        Matrix::new(self.nrows, self.ncols, self.data.iter().map(f).collect())
+    }
+
+    /// The first position, row by row, whose element matches `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Vec2<usize>> {
+        self.indexed_iter()
+            .find(|(_, element)| predicate(element))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Every position holding `value`, row by row.
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Vec2<usize>> + 'a
+    where
+        T: PartialEq,
+    {
+        self.indexed_iter()
+            .filter(move |(_, element)| *element == value)
+            .map(|(pos, _)| pos)
+    }
+
     pub fn new(nrows: usize, ncols: usize, data: Vec<T>) -> Self {
         Self { nrows, ncols, data }
     }
 
     pub fn get_element(&self, row: usize, col: usize) -> Option<&T> {
-        return if self.check_coords(row as isize, col as isize) {
+        if self.check_coords(row as isize, col as isize) {
             Some(&self.data[row * self.ncols + col])
         } else {
             None
-        };
+        }
     }
 
     pub fn get_element_signed(&self, row: isize, col: isize) -> Option<&T> {
-        return if self.check_coords(row as isize, col as isize) {
+        if self.check_coords(row, col) {
             Some(&self.data[row as usize * self.ncols + col as usize])
         } else {
             None
-        };
+        }
     }
 
     pub fn get_mut_element(&mut self, row: usize, col: usize) -> Option<&mut T> {
-        return if self.check_coords(row as isize, col as isize) {
+        if self.check_coords(row as isize, col as isize) {
             Some(&mut self.data[row * self.ncols + col])
         } else {
             None
-        };
+        }
     }
 
     pub fn get_mut_element_signed(&mut self, row: isize, col: isize) -> Option<&mut T> {
-        return if self.check_coords(row as isize, col as isize) {
+        if self.check_coords(row, col) {
             Some(&mut self.data[row as usize * self.ncols + col as usize])
         } else {
             None
-        };
+        }
     }
 
     fn check_coords(&self, row: isize, col: isize) -> bool {
         row >= 0 && col >= 0 && row < self.nrows as isize && col < self.ncols as isize
     }
 
+    /// A `1 x ncols` copy of the row, [`Matrix::row`] borrows it instead.
     pub fn get_row(&self, row: usize) -> Matrix<&T> {
-        let mut data = vec![];
-        for col in 0..self.ncols {
-            data.push(&self.data[row * self.ncols + col]);
-        }
-        Matrix::new(1, self.nrows, data)
+        Matrix::new(1, self.ncols, self.row(row).iter().collect())
     }
 
+    /// A `nrows x 1` copy of the column, [`Matrix::col`] borrows it instead.
     pub fn get_col(&self, col: usize) -> Matrix<&T> {
-        let mut data = vec![];
-        for row in 0..self.nrows {
-            data.push(&self.data[row * self.ncols + col]);
-        }
-        Matrix::new(1, self.ncols, data)
+        Matrix::new(self.nrows, 1, self.col(col).iter().collect())
+    }
+}
+
+/// `matrix[(row, col)]`, panics outside the matrix.
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(col < self.ncols, "column {col} of {}", self.ncols);
+        &self.data[row * self.ncols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(col < self.ncols, "column {col} of {}", self.ncols);
+        &mut self.data[row * self.ncols + col]
+    }
+}
+
+/// `matrix[pos]` with `x` the column and `y` the row, panics outside the matrix.
+impl<T> Index<Vec2<usize>> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vec2<usize>) -> &T {
+        &self[(pos.y, pos.x)]
+    }
+}
+
+impl<T> IndexMut<Vec2<usize>> for Matrix<T> {
+    fn index_mut(&mut self, pos: Vec2<usize>) -> &mut T {
+        &mut self[(pos.y, pos.x)]
     }
 }
 
@@ -113,22 +191,224 @@ impl<T: Clone> Matrix<T> {
 impl<T: Clone> Clone for Matrix<T> {
     fn clone(&self) -> Self {
         Self {
-            nrows: self.nrows.clone(),
-            ncols: self.ncols.clone(),
+            nrows: self.nrows,
+            ncols: self.ncols,
             data: self.data.clone(),
         }
     }
 }
 
 impl<T> Matrix<T> {
+    /// Panics unless `iter` yields exactly `nrows * ncols` elements, row by row.
     pub fn from_iterator<I: Iterator<Item = T>>(nrows: usize, ncols: usize, iter: I) -> Self {
-        let mut data = Vec::new();
-        let mut iter = iter;
-        while let Some(next) = iter.next() {
-            data.push(next);
-        }
+        let data: Vec<T> = iter.collect();
+        assert_eq!(
+            data.len(),
+            nrows * ncols,
+            "a {nrows}x{ncols} matrix needs {} elements",
+            nrows * ncols
+        );
         Self { nrows, ncols, data }
     }
+
+    /// A puzzle map with one element per char, see [`Matrix::try_parse_grid`].
+    pub fn parse_grid(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, AocError> {
+        Self::try_parse_grid(input, |char| Ok::<_, Infallible>(cell(char)))
+    }
+
+    /// Parses a puzzle map with one element per char, rows end in `\n` or `\r\n`.
+    ///
+    /// Trailing blank lines are ignored, every other row must be as wide as the first.
+    /// A char that `cell` rejects is reported where it is, with `cell`'s error as the message.
+    pub fn try_parse_grid<E: Display>(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, AocError> {
+        let rows: Vec<&str> = input
+            .lines()
+            .map(|line| line.strip_suffix('\r').unwrap_or(line))
+            .collect();
+        let nrows = rows
+            .iter()
+            .rposition(|row| !row.is_empty())
+            .map_or(0, |i| i + 1);
+        let ncols = rows.first().map_or(0, |row| row.chars().count());
+
+        let mut data = Vec::with_capacity(nrows * ncols);
+        for (i, row) in rows[..nrows].iter().enumerate() {
+            let width = row.chars().count();
+            if width != ncols {
+                // point at the first missing or extra cell
+                let at = row
+                    .char_indices()
+                    .nth(ncols)
+                    .map_or(row.len(), |(at, _)| at);
+                return Err(AocError::at_rest(
+                    input,
+                    &row[at..],
+                    format!("row {} is {width} wide, expected {ncols}", i + 1),
+                ));
+            }
+            for (at, char) in row.char_indices() {
+                let element = cell(char)
+                    .map_err(|err| AocError::at_rest(input, &row[at..], err.to_string()))?;
+                data.push(element);
+            }
+        }
+        Ok(Self { nrows, ncols, data })
+    }
+}
+
+/// Geometric transforms, each in place or as a transformed copy.
+impl<T> Matrix<T> {
+    /// Swaps rows and columns, row `i` becomes column `i`.
+    pub fn transpose(&mut self) {
+        let (nrows, ncols) = (self.nrows, self.ncols);
+        let mut data: Vec<Option<T>> = std::mem::take(&mut self.data)
+            .into_iter()
+            .map(Some)
+            .collect();
+        // element (row, col) of the result is (col, row) of the original
+        self.data = (0..nrows * ncols)
+            .map(|i| {
+                let (row, col) = (i / nrows, i % nrows);
+                data[col * ncols + row]
+                    .take()
+                    .expect("each element moves once")
+            })
+            .collect();
+        self.nrows = ncols;
+        self.ncols = nrows;
+    }
+
+    /// Rotates a quarter turn clockwise, the top row becomes the right column.
+    pub fn rotate_cw(&mut self) {
+        self.transpose();
+        self.flip_horizontal();
+    }
+
+    /// Rotates a quarter turn counter-clockwise, the top row becomes the left column.
+    pub fn rotate_ccw(&mut self) {
+        self.transpose();
+        self.flip_vertical();
+    }
+
+    /// Mirrors left to right, every row is reversed.
+    pub fn flip_horizontal(&mut self) {
+        if self.ncols > 0 {
+            for row in self.data.chunks_mut(self.ncols) {
+                row.reverse();
+            }
+        }
+    }
+
+    /// Mirrors top to bottom, the order of the rows is reversed.
+    pub fn flip_vertical(&mut self) {
+        for row in 0..self.nrows / 2 {
+            let other = self.nrows - 1 - row;
+            for col in 0..self.ncols {
+                self.data
+                    .swap(row * self.ncols + col, other * self.ncols + col);
+            }
+        }
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    pub fn transposed(&self) -> Self {
+        let mut matrix = self.clone();
+        matrix.transpose();
+        matrix
+    }
+
+    pub fn rotated_cw(&self) -> Self {
+        let mut matrix = self.clone();
+        matrix.rotate_cw();
+        matrix
+    }
+
+    pub fn rotated_ccw(&self) -> Self {
+        let mut matrix = self.clone();
+        matrix.rotate_ccw();
+        matrix
+    }
+
+    pub fn flipped_horizontal(&self) -> Self {
+        let mut matrix = self.clone();
+        matrix.flip_horizontal();
+        matrix
+    }
+
+    pub fn flipped_vertical(&self) -> Self {
+        let mut matrix = self.clone();
+        matrix.flip_vertical();
+        matrix
+    }
+}
+
+/// Neighbours take positions as `x` the column and `y` the row, like [`Vec2`]'s directions.
+impl<T> Matrix<T> {
+    /// The cell `offset` away from `pos`, if that's still inside the matrix.
+    pub fn neighbour(&self, pos: Vec2<usize>, offset: Vec2<isize>) -> Option<(Vec2<usize>, &T)> {
+        let pos = pos.checked_add_signed(offset)?;
+        let element = self.get_element(pos.y, pos.x)?;
+        Some((pos, element))
+    }
+
+    /// The up to 4 cells up, right, down and left of `pos`, in that order.
+    pub fn neighbours4(&self, pos: Vec2<usize>) -> impl Iterator<Item = (Vec2<usize>, &T)> {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |offset| self.neighbour(pos, offset))
+    }
+
+    /// The up to 8 cells around `pos`, diagonals included, in reading order.
+    pub fn neighbours8(&self, pos: Vec2<usize>) -> impl Iterator<Item = (Vec2<usize>, &T)> {
+        ADJACENT
+            .into_iter()
+            .filter_map(move |offset| self.neighbour(pos, offset))
+    }
+
+    /// `pos` on an infinite plane tiled with copies of the matrix, mapped back into it.
+    pub fn wrap(&self, pos: Vec2<isize>) -> Vec2<usize> {
+        assert!(
+            self.nrows > 0 && self.ncols > 0,
+            "can't wrap around an empty matrix"
+        );
+        Vec2::new(
+            pos.x.rem_euclid(self.ncols as isize) as usize,
+            pos.y.rem_euclid(self.nrows as isize) as usize,
+        )
+    }
+
+    /// The element at `pos` on an infinite plane tiled with copies of the matrix.
+    pub fn get_wrapping(&self, pos: Vec2<isize>) -> &T {
+        let pos = self.wrap(pos);
+        &self.data[pos.y * self.ncols + pos.x]
+    }
+
+    /// Like [`Matrix::neighbours4`] on the tiled plane: every neighbour exists, positions aren't
+    /// wrapped, only the lookup is.
+    pub fn neighbours4_wrapping(
+        &self,
+        pos: Vec2<isize>,
+    ) -> impl Iterator<Item = (Vec2<isize>, &T)> {
+        ORTHOGONAL.into_iter().map(move |offset| {
+            let pos = pos + offset;
+            (pos, self.get_wrapping(pos))
+        })
+    }
+
+    /// Like [`Matrix::neighbours8`] on the tiled plane, see [`Matrix::neighbours4_wrapping`].
+    pub fn neighbours8_wrapping(
+        &self,
+        pos: Vec2<isize>,
+    ) -> impl Iterator<Item = (Vec2<isize>, &T)> {
+        ADJACENT.into_iter().map(move |offset| {
+            let pos = pos + offset;
+            (pos, self.get_wrapping(pos))
+        })
+    }
 }
 
 #[cfg(test)]
@@ -147,9 +427,185 @@ mod tests {
         assert_eq!(m.get_element(2, 2), Some(&1));
         assert_eq!(m.get_element(3, 3), None);
 
-        assert_eq!(m.get_col(0).to_owned(), Matrix::new(1, 3, vec![9, 6, 3]));
-        assert_eq!(m.get_col(1).to_owned(), Matrix::new(1, 3, vec![8, 5, 2]));
+        assert_eq!(m.get_col(0).to_owned(), Matrix::new(3, 1, vec![9, 6, 3]));
+        assert_eq!(m.get_col(1).to_owned(), Matrix::new(3, 1, vec![8, 5, 2]));
         assert_eq!(m.get_row(0).to_owned(), Matrix::new(1, 3, vec![9, 8, 7]));
         assert_eq!(m.get_row(1).to_owned(), Matrix::new(1, 3, vec![6, 5, 4]));
     }
+
+    #[test]
+    #[should_panic(expected = "a 2x2 matrix needs 4 elements")]
+    fn from_iterator_checks_len() {
+        Matrix::from_iterator(2, 2, [1, 2, 3].into_iter());
+    }
+
+    #[test]
+    fn parse_grid() {
+        let m = Matrix::parse_grid("#.\r\n.#\r\n\n\n", |c| c == '#').unwrap();
+        assert_eq!(m, Matrix::new(2, 2, vec![true, false, false, true]));
+
+        let m = Matrix::try_parse_grid("12\n34", |c| c.to_digit(10).ok_or("not a digit")).unwrap();
+        assert_eq!(m, Matrix::new(2, 2, vec![1, 2, 3, 4]));
+
+        let err = Matrix::try_parse_grid("12\n3x", |c| c.to_digit(10).ok_or("not a digit"));
+        assert!(matches!(
+            err,
+            Err(AocError::Parse { line: 2, column: 2, ref message, .. }) if message == "not a digit"
+        ));
+
+        assert_eq!(Matrix::parse_grid("", |c| c).unwrap().nrows(), 0);
+    }
+
+    #[test]
+    fn index() {
+        let mut m = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(m[(1, 0)], 4);
+        assert_eq!(m[Vec2::new(1, 0)], 2);
+
+        m[(0, 2)] = 30;
+        m[Vec2::new(0, 1)] *= 10;
+        for element in m.iter_mut() {
+            *element += 1;
+        }
+        assert_eq!(m, Matrix::new(2, 3, vec![2, 3, 31, 41, 6, 7]));
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 of 3")]
+    fn index_checks_col() {
+        // would otherwise wrap around to (1, 0)
+        let m = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        let _ = m[(0, 3)];
+    }
+
+    #[test]
+    fn indexed_iter() {
+        let m = Matrix::new(2, 2, vec!['S', '.', '.', 'S']);
+        let positions: Vec<_> = m.indexed_iter().map(|(pos, _)| pos).collect();
+        assert_eq!(
+            positions,
+            [
+                Vec2::new(0, 0),
+                Vec2::new(1, 0),
+                Vec2::new(0, 1),
+                Vec2::new(1, 1)
+            ]
+        );
+
+        assert_eq!(m.position(|&c| c == '.'), Some(Vec2::new(1, 0)));
+        assert_eq!(m.position(|&c| c == '#'), None);
+        assert_eq!(
+            m.positions_of(&'S').collect::<Vec<_>>(),
+            [Vec2::new(0, 0), Vec2::new(1, 1)]
+        );
+        assert_eq!(
+            m.map(|&c| c == 'S'),
+            Matrix::new(2, 2, vec![true, false, false, true])
+        );
+    }
+
+    #[test]
+    fn transforms() {
+        // 1 2 3
+        // 4 5 6
+        let m = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(m.transposed(), Matrix::new(3, 2, vec![1, 4, 2, 5, 3, 6]));
+        assert_eq!(m.rotated_cw(), Matrix::new(3, 2, vec![4, 1, 5, 2, 6, 3]));
+        assert_eq!(m.rotated_ccw(), Matrix::new(3, 2, vec![3, 6, 2, 5, 1, 4]));
+        assert_eq!(
+            m.flipped_horizontal(),
+            Matrix::new(2, 3, vec![3, 2, 1, 6, 5, 4])
+        );
+        assert_eq!(
+            m.flipped_vertical(),
+            Matrix::new(2, 3, vec![4, 5, 6, 1, 2, 3])
+        );
+
+        let mut turned = m.clone();
+        for _ in 0..4 {
+            turned.rotate_cw();
+        }
+        assert_eq!(turned, m);
+        turned.rotate_cw();
+        turned.rotate_ccw();
+        assert_eq!(turned, m);
+        turned.transpose();
+        turned.transpose();
+        assert_eq!(turned, m);
+
+        // in place works without Clone
+        let mut names = Matrix::new(1, 2, vec![String::from("a"), String::from("b")]);
+        names.rotate_ccw();
+        assert_eq!((names.nrows(), names.ncols()), (2, 1));
+        assert_eq!(names.get_element(0, 0).map(String::as_str), Some("b"));
+    }
+
+    #[test]
+    fn neighbours() {
+        // 1 2 3
+        // 4 5 6
+        let m = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        let values = |it: &mut dyn Iterator<Item = (Vec2<usize>, &i32)>| {
+            it.map(|(_, &v)| v).collect::<Vec<_>>()
+        };
+
+        assert_eq!(values(&mut m.neighbours4(Vec2::new(1, 0))), [3, 5, 1]);
+        assert_eq!(values(&mut m.neighbours4(Vec2::new(0, 1))), [1, 5]);
+        assert_eq!(values(&mut m.neighbours8(Vec2::new(1, 1))), [1, 2, 3, 4, 6]);
+        assert_eq!(
+            m.neighbours4(Vec2::new(2, 1)).next(),
+            Some((Vec2::new(2, 0), &3))
+        );
+        assert_eq!(m.neighbour(Vec2::new(0, 0), Vec2::LEFT), None);
+    }
+
+    #[test]
+    fn neighbours_wrapping() {
+        let m = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(m.wrap(Vec2::new(-1, -1)), Vec2::new(2, 1));
+        assert_eq!(m.get_wrapping(Vec2::new(7, 4)), &2);
+
+        let around: Vec<_> = m.neighbours4_wrapping(Vec2::new(0, 0)).collect();
+        assert_eq!(
+            around,
+            [
+                (Vec2::new(0, -1), &4),
+                (Vec2::new(1, 0), &2),
+                (Vec2::new(0, 1), &4),
+                (Vec2::new(-1, 0), &3)
+            ]
+        );
+        assert_eq!(m.neighbours8_wrapping(Vec2::new(5, 5)).count(), 8);
+    }
+
+    #[test]
+    fn parse_grid_ragged() {
+        let err = Matrix::parse_grid("abc\nab\nabc", |c| c).unwrap_err();
+        assert_eq!(
+            err,
+            AocError::Parse {
+                line: 2,
+                column: 3,
+                snippet: "ab".to_owned(),
+                message: "row 2 is 2 wide, expected 3".to_owned()
+            }
+        );
+
+        let err = Matrix::parse_grid("ab\r\nabc\r\n", |c| c).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                AocError::Parse {
+                    line: 2,
+                    column: 3,
+                    ..
+                }
+            ),
+            "{err:?}"
+        );
+
+        // a blank line in the middle isn't trailing
+        assert!(Matrix::parse_grid("ab\n\nab", |c| c).is_err());
+    }
 }
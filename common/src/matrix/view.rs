@@ -0,0 +1,458 @@
+use std::{
+    iter::{Skip, StepBy},
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use super::Matrix;
+
+/// A borrowed row of a [`Matrix`], see [`Matrix::row`].
+#[derive(Debug, Clone, Copy)]
+pub struct RowView<'a, T> {
+    cells: &'a [T],
+}
+
+/// A borrowed column of a [`Matrix`], see [`Matrix::col`].
+#[derive(Debug)]
+pub struct ColView<'a, T> {
+    data: &'a [T],
+    ncols: usize,
+    col: usize,
+}
+
+/// A mutably borrowed row of a [`Matrix`], see [`Matrix::row_mut`].
+#[derive(Debug)]
+pub struct RowViewMut<'a, T> {
+    cells: &'a mut [T],
+}
+
+/// A mutably borrowed column of a [`Matrix`], see [`Matrix::col_mut`].
+#[derive(Debug)]
+pub struct ColViewMut<'a, T> {
+    data: &'a mut [T],
+    ncols: usize,
+    col: usize,
+}
+
+impl<T> Clone for ColView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ColView<'_, T> {}
+
+impl<'a, T> RowView<'a, T> {
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, col: usize) -> Option<&'a T> {
+        self.cells.get(col)
+    }
+
+    pub fn iter(&self) -> slice::Iter<'a, T> {
+        self.cells.iter()
+    }
+
+    pub fn as_slice(&self) -> &'a [T] {
+        self.cells
+    }
+}
+
+impl<'a, T> ColView<'a, T> {
+    pub fn len(&self) -> usize {
+        self.data.len() / self.ncols
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn get(&self, row: usize) -> Option<&'a T> {
+        self.data.get(row * self.ncols + self.col)
+    }
+
+    pub fn iter(&self) -> StepBy<Skip<slice::Iter<'a, T>>> {
+        self.data.iter().skip(self.col).step_by(self.ncols)
+    }
+}
+
+impl<T> RowViewMut<'_, T> {
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, col: usize) -> Option<&T> {
+        self.cells.get(col)
+    }
+
+    pub fn get_mut(&mut self, col: usize) -> Option<&mut T> {
+        self.cells.get_mut(col)
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+}
+
+impl<T> ColViewMut<'_, T> {
+    pub fn len(&self) -> usize {
+        self.data.len() / self.ncols
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn get(&self, row: usize) -> Option<&T> {
+        self.data.get(row * self.ncols + self.col)
+    }
+
+    pub fn get_mut(&mut self, row: usize) -> Option<&mut T> {
+        self.data.get_mut(row * self.ncols + self.col)
+    }
+
+    pub fn iter(&self) -> StepBy<Skip<slice::Iter<'_, T>>> {
+        self.data.iter().skip(self.col).step_by(self.ncols)
+    }
+
+    pub fn iter_mut(&mut self) -> StepBy<Skip<slice::IterMut<'_, T>>> {
+        self.data.iter_mut().skip(self.col).step_by(self.ncols)
+    }
+}
+
+impl<T> Index<usize> for RowView<'_, T> {
+    type Output = T;
+
+    fn index(&self, col: usize) -> &T {
+        &self.cells[col]
+    }
+}
+
+impl<T> Index<usize> for ColView<'_, T> {
+    type Output = T;
+
+    fn index(&self, row: usize) -> &T {
+        self.get(row).expect("row should be inside the column")
+    }
+}
+
+impl<T> Index<usize> for RowViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, col: usize) -> &T {
+        &self.cells[col]
+    }
+}
+
+impl<T> IndexMut<usize> for RowViewMut<'_, T> {
+    fn index_mut(&mut self, col: usize) -> &mut T {
+        &mut self.cells[col]
+    }
+}
+
+impl<T> Index<usize> for ColViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, row: usize) -> &T {
+        self.get(row).expect("row should be inside the column")
+    }
+}
+
+impl<T> IndexMut<usize> for ColViewMut<'_, T> {
+    fn index_mut(&mut self, row: usize) -> &mut T {
+        self.get_mut(row).expect("row should be inside the column")
+    }
+}
+
+impl<'a, T> IntoIterator for RowView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for ColView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = StepBy<Skip<slice::Iter<'a, T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// Rows and columns compare by their elements, so a row can equal a column.
+impl<T: PartialEq> PartialEq for RowView<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<T: PartialEq> PartialEq for ColView<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq> PartialEq<ColView<'_, T>> for RowView<'_, T> {
+    fn eq(&self, other: &ColView<'_, T>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq> PartialEq<RowView<'_, T>> for ColView<'_, T> {
+    fn eq(&self, other: &RowView<'_, T>) -> bool {
+        other == self
+    }
+}
+
+impl<T: Eq> Eq for RowView<'_, T> {}
+
+impl<T: Eq> Eq for ColView<'_, T> {}
+
+impl<T> Matrix<T> {
+    /// Panics if `row` is out of bounds.
+    pub fn row(&self, row: usize) -> RowView<'_, T> {
+        assert!(row < self.nrows, "row {row} of {}", self.nrows);
+        RowView {
+            cells: &self.data[row * self.ncols..(row + 1) * self.ncols],
+        }
+    }
+
+    /// Panics if `col` is out of bounds.
+    pub fn col(&self, col: usize) -> ColView<'_, T> {
+        assert!(col < self.ncols, "column {col} of {}", self.ncols);
+        ColView {
+            data: &self.data,
+            ncols: self.ncols,
+            col,
+        }
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> RowViewMut<'_, T> {
+        assert!(row < self.nrows, "row {row} of {}", self.nrows);
+        RowViewMut {
+            cells: &mut self.data[row * self.ncols..(row + 1) * self.ncols],
+        }
+    }
+
+    pub fn col_mut(&mut self, col: usize) -> ColViewMut<'_, T> {
+        assert!(col < self.ncols, "column {col} of {}", self.ncols);
+        ColViewMut {
+            data: &mut self.data,
+            ncols: self.ncols,
+            col,
+        }
+    }
+
+    /// Top to bottom.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = RowView<'_, T>> + ExactSizeIterator {
+        (0..self.nrows).map(|row| self.row(row))
+    }
+
+    /// Left to right.
+    pub fn cols(&self) -> impl DoubleEndedIterator<Item = ColView<'_, T>> + ExactSizeIterator {
+        (0..self.ncols).map(|col| self.col(col))
+    }
+
+    /// Inserts `cells` as row `at`, moving the rows below down.
+    ///
+    /// Panics unless there are `ncols` cells, a matrix without cells takes any width and ends
+    /// up with just this row.
+    pub fn insert_row(&mut self, at: usize, cells: impl IntoIterator<Item = T>) {
+        assert!(at <= self.nrows, "can't insert row {at} of {}", self.nrows);
+        let cells: Vec<T> = cells.into_iter().collect();
+        if self.data.is_empty() {
+            // an Nx0 matrix has no rows to keep
+            (self.nrows, self.ncols) = (1, cells.len());
+            self.data = cells;
+            return;
+        }
+        assert_eq!(cells.len(), self.ncols, "a row needs {} cells", self.ncols);
+        let start = at * self.ncols;
+        self.data.splice(start..start, cells);
+        self.nrows += 1;
+    }
+
+    /// Inserts `cells` as column `at`, moving the columns to its right along.
+    ///
+    /// Panics unless there are `nrows` cells, a matrix without cells takes any height and ends
+    /// up with just this column.
+    pub fn insert_col(&mut self, at: usize, cells: impl IntoIterator<Item = T>) {
+        assert!(
+            at <= self.ncols,
+            "can't insert column {at} of {}",
+            self.ncols
+        );
+        let cells: Vec<T> = cells.into_iter().collect();
+        if self.data.is_empty() {
+            // a 0xN matrix has no columns to keep
+            (self.nrows, self.ncols) = (cells.len(), 1);
+            self.data = cells;
+            return;
+        }
+        assert_eq!(
+            cells.len(),
+            self.nrows,
+            "a column needs {} cells",
+            self.nrows
+        );
+
+        let ncols = self.ncols;
+        let mut old = std::mem::take(&mut self.data).into_iter();
+        let mut data = Vec::with_capacity(self.nrows * (ncols + 1));
+        for cell in cells {
+            data.extend(old.by_ref().take(at));
+            data.push(cell);
+            data.extend(old.by_ref().take(ncols - at));
+        }
+        self.data = data;
+        self.ncols += 1;
+    }
+
+    /// Removes row `at` and returns its cells, moving the rows below up.
+    pub fn remove_row(&mut self, at: usize) -> Vec<T> {
+        assert!(at < self.nrows, "can't remove row {at} of {}", self.nrows);
+        let start = at * self.ncols;
+        let cells = self.data.drain(start..start + self.ncols).collect();
+        self.nrows -= 1;
+        if self.nrows == 0 {
+            self.ncols = 0;
+        }
+        cells
+    }
+
+    /// Removes column `at` and returns its cells, moving the columns to its right along.
+    pub fn remove_col(&mut self, at: usize) -> Vec<T> {
+        assert!(
+            at < self.ncols,
+            "can't remove column {at} of {}",
+            self.ncols
+        );
+        let ncols = self.ncols;
+        let mut cells = Vec::with_capacity(self.nrows);
+        let mut data = Vec::with_capacity(self.nrows * (ncols - 1));
+        for (i, cell) in std::mem::take(&mut self.data).into_iter().enumerate() {
+            if i % ncols == at {
+                cells.push(cell);
+            } else {
+                data.push(cell);
+            }
+        }
+        self.data = data;
+        self.ncols -= 1;
+        if self.ncols == 0 {
+            self.nrows = 0;
+        }
+        cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 1 2 3
+    // 4 5 6
+    fn matrix() -> Matrix<i32> {
+        Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6])
+    }
+
+    #[test]
+    fn views() {
+        let m = matrix();
+        assert_eq!(m.row(1).iter().copied().collect::<Vec<_>>(), [4, 5, 6]);
+        assert_eq!(m.col(2).iter().copied().collect::<Vec<_>>(), [3, 6]);
+        assert_eq!((m.row(0).len(), m.col(0).len()), (3, 2));
+        assert_eq!((m.row(0)[2], m.col(1)[1]), (3, 5));
+        assert_eq!(m.col(1).get(2), None);
+
+        assert_eq!(m.rows().len(), 2);
+        assert_eq!(m.cols().next_back(), Some(m.col(2)));
+        assert_ne!(m.row(0), m.row(1));
+
+        let square = Matrix::new(2, 2, vec![1, 2, 2, 1]);
+        assert_eq!(square.row(0), square.col(0));
+        assert_eq!(square.col(1), square.row(1));
+    }
+
+    #[test]
+    fn views_mut() {
+        let mut m = matrix();
+        m.row_mut(0)[1] = 20;
+        for cell in m.col_mut(2).iter_mut() {
+            *cell *= 10;
+        }
+        *m.col_mut(0).get_mut(1).unwrap() = 40;
+        assert_eq!(m, Matrix::new(2, 3, vec![1, 20, 30, 40, 5, 60]));
+    }
+
+    #[test]
+    fn get_row_and_col_shapes() {
+        let m = matrix();
+        assert_eq!(m.get_row(1).to_owned(), Matrix::new(1, 3, vec![4, 5, 6]));
+        assert_eq!(m.get_col(1).to_owned(), Matrix::new(2, 1, vec![2, 5]));
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut m = matrix();
+        m.insert_row(1, [7, 8, 9]);
+        assert_eq!(m, Matrix::new(3, 3, vec![1, 2, 3, 7, 8, 9, 4, 5, 6]));
+        assert_eq!(m.remove_row(0), [1, 2, 3]);
+
+        m.insert_col(3, [0, 0]);
+        m.insert_col(0, [-1, -2]);
+        assert_eq!(m, Matrix::new(2, 5, vec![-1, 7, 8, 9, 0, -2, 4, 5, 6, 0]));
+        assert_eq!(m.remove_col(2), [8, 5]);
+        assert_eq!(m, Matrix::new(2, 4, vec![-1, 7, 9, 0, -2, 4, 6, 0]));
+
+        let mut empty = Matrix::new(0, 0, vec![]);
+        empty.insert_row(0, [1, 2]);
+        assert_eq!(empty, Matrix::new(1, 2, vec![1, 2]));
+    }
+
+    #[test]
+    fn insert_into_no_cells() {
+        // 0x3, columns without rows
+        let mut m = Matrix::new(0, 3, vec![]);
+        m.insert_col(2, [1, 2]);
+        assert_eq!(m, Matrix::new(2, 1, vec![1, 2]));
+        assert_eq!(m[(1, 0)], 2);
+
+        // 2x0, left behind by removing every column
+        let mut m = Matrix::new(2, 1, vec![1, 2]);
+        m.remove_col(0);
+        assert_eq!((m.nrows(), m.ncols()), (0, 0));
+        let mut m = Matrix::new(2, 0, vec![]);
+        m.insert_row(1, [3, 4, 5]);
+        assert_eq!(m, Matrix::new(1, 3, vec![3, 4, 5]));
+        assert_eq!(m.get_element(0, 2), Some(&5));
+
+        let mut m = Matrix::new(1, 2, vec![1, 2]);
+        m.remove_row(0);
+        m.insert_col(0, [7]);
+        assert_eq!(m, Matrix::new(1, 1, vec![7]));
+    }
+
+    #[test]
+    #[should_panic(expected = "a row needs 3 cells")]
+    fn insert_row_checks_width() {
+        matrix().insert_row(0, [1, 2]);
+    }
+}
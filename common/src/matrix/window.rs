@@ -0,0 +1,188 @@
+use std::ops::Index;
+
+use super::Matrix;
+use crate::Vec2;
+
+/// A borrowed rectangle of a [`Matrix`], see [`Matrix::window`].
+///
+/// Positions are relative to the window's top left corner, at [`Window::origin`] in the matrix.
+#[derive(Debug)]
+pub struct Window<'a, T> {
+    matrix: &'a Matrix<T>,
+    row: usize,
+    col: usize,
+    nrows: usize,
+    ncols: usize,
+}
+
+impl<T> Clone for Window<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Window<'_, T> {}
+
+impl<'a, T> Window<'a, T> {
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nrows == 0 || self.ncols == 0
+    }
+
+    /// Where the top left corner is in the matrix, `x` the column and `y` the row.
+    pub fn origin(&self) -> Vec2<usize> {
+        Vec2::new(self.col, self.row)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row < self.nrows && col < self.ncols {
+            self.matrix.get_element(self.row + row, self.col + col)
+        } else {
+            None
+        }
+    }
+
+    /// Top to bottom, each row a slice of the matrix.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &'a [T]> + ExactSizeIterator {
+        let Window {
+            matrix,
+            row,
+            col,
+            nrows,
+            ncols,
+        } = *self;
+        (row..row + nrows).map(move |row| &matrix.row(row).as_slice()[col..col + ncols])
+    }
+
+    /// Row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        self.rows().flatten()
+    }
+
+    /// Row by row, with each element's position in the window.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Vec2<usize>, &'a T)> {
+        self.rows().enumerate().flat_map(|(y, row)| {
+            (row.iter().enumerate()).map(move |(x, element)| (Vec2::new(x, y), element))
+        })
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        Matrix::from_iterator(self.nrows, self.ncols, self.iter().cloned())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Window<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col).unwrap_or_else(|| {
+            panic!(
+                "({row}, {col}) is outside the {}x{} window",
+                self.nrows, self.ncols
+            )
+        })
+    }
+}
+
+// Windows compare by shape and elements, wherever they are and whichever matrix they are of.
+impl<T: PartialEq> PartialEq for Window<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.nrows, self.ncols) == (other.nrows, other.ncols) && self.rows().eq(other.rows())
+    }
+}
+
+impl<T: Eq> Eq for Window<'_, T> {}
+
+impl<T: PartialEq> PartialEq<Matrix<T>> for Window<'_, T> {
+    fn eq(&self, other: &Matrix<T>) -> bool {
+        *self == other.window(0, 0, other.nrows(), other.ncols())
+    }
+}
+
+impl<T> Matrix<T> {
+    /// The `nrows` by `ncols` rectangle from (`row`, `col`), cut down to what is inside the
+    /// matrix, so it may be smaller than asked for or even empty.
+    pub fn window(&self, row: usize, col: usize, nrows: usize, ncols: usize) -> Window<'_, T> {
+        let row = row.min(self.nrows);
+        let col = col.min(self.ncols);
+        Window {
+            matrix: self,
+            row,
+            col,
+            nrows: nrows.min(self.nrows - row),
+            ncols: ncols.min(self.ncols - col),
+        }
+    }
+
+    /// Every `nrows` by `ncols` window that fits, row by row, none if the matrix is smaller.
+    ///
+    /// Panics if either size is 0.
+    pub fn windows(&self, nrows: usize, ncols: usize) -> impl Iterator<Item = Window<'_, T>> {
+        assert!(nrows > 0 && ncols > 0, "windows must not be empty");
+        let rows = (self.nrows + 1).saturating_sub(nrows);
+        let cols = (self.ncols + 1).saturating_sub(ncols);
+        (0..rows).flat_map(move |row| (0..cols).map(move |col| self.window(row, col, nrows, ncols)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 1 2 3
+    // 4 5 6
+    // 7 8 9
+    fn matrix() -> Matrix<i32> {
+        Matrix::new(3, 3, (1..=9).collect())
+    }
+
+    #[test]
+    fn window() {
+        let m = matrix();
+        let w = m.window(1, 1, 2, 2);
+        assert_eq!(w.to_matrix(), Matrix::new(2, 2, vec![5, 6, 8, 9]));
+        assert_eq!((w[(0, 1)], w.get(2, 0)), (6, None));
+        assert_eq!(w.origin(), Vec2::new(1, 1));
+        assert_eq!(
+            w.indexed_iter().nth(2),
+            Some((Vec2::new(0, 1), &8)),
+            "positions are relative to the window"
+        );
+
+        let clamped = m.window(2, 1, 5, 5);
+        assert_eq!((clamped.nrows(), clamped.ncols()), (1, 2));
+        assert_eq!(clamped.iter().copied().collect::<Vec<_>>(), [8, 9]);
+        assert!(m.window(3, 0, 1, 1).is_empty());
+        assert!(m.window(7, 7, 1, 1).is_empty());
+    }
+
+    #[test]
+    fn equality() {
+        let m = Matrix::new(2, 4, vec![1, 2, 1, 2, 3, 4, 3, 4]);
+        assert_eq!(m.window(0, 0, 2, 2), m.window(0, 2, 2, 2));
+        assert_ne!(m.window(0, 0, 2, 2), m.window(0, 1, 2, 2));
+        // same elements, different shape
+        assert_ne!(m.window(0, 0, 1, 4), m.window(0, 0, 2, 2));
+        assert_eq!(m.window(0, 1, 2, 2), Matrix::new(2, 2, vec![2, 1, 4, 3]));
+    }
+
+    #[test]
+    fn windows() {
+        let m = matrix();
+        let corners: Vec<_> = m.windows(2, 2).map(|w| w[(0, 0)]).collect();
+        assert_eq!(corners, [1, 2, 4, 5]);
+        assert_eq!(m.windows(3, 1).count(), 3);
+        assert_eq!(m.windows(4, 1).count(), 0);
+        assert!(m.windows(2, 3).all(|w| w.nrows() == 2 && w.ncols() == 3));
+    }
+}
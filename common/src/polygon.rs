@@ -0,0 +1,293 @@
+use crate::{Dir4, Vec2};
+
+/// A closed polygon with its corners on the integer grid, y pointing down.
+///
+/// Corners are `i64` and everything derived from them is worked out in `i128`, so puzzle sized
+/// coordinates can't overflow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Vec2<i64>>,
+}
+
+impl Polygon {
+    /// The last vertex joins back up with the first.
+    pub fn new(vertices: Vec<Vec2<i64>>) -> Self {
+        Self { vertices }
+    }
+
+    /// A rectilinear polygon walked from `start`, each move is a direction and a length.
+    ///
+    /// Moves that don't end back at `start` are closed with one last edge, zero length moves
+    /// are skipped.
+    pub fn from_moves(start: Vec2<i64>, moves: impl IntoIterator<Item = (Dir4, i64)>) -> Self {
+        let mut vertices = vec![start];
+        let mut pos = start;
+        for (dir, len) in moves {
+            if len == 0 {
+                continue;
+            }
+            let offset = dir.offset();
+            pos += Vec2::new(offset.x as i64, offset.y as i64) * len;
+            vertices.push(pos);
+        }
+        if vertices.len() > 1 && pos == start {
+            vertices.pop();
+        }
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Vec2<i64>] {
+        &self.vertices
+    }
+
+    /// Each edge as its two ends, the last one back to the first vertex.
+    pub fn edges(&self) -> impl Iterator<Item = (Vec2<i64>, Vec2<i64>)> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    /// Twice the area with the shoelace formula, always a whole number. Positive when the
+    /// vertices go clockwise on screen, negative when they go anticlockwise.
+    ///
+    /// <https://en.wikipedia.org/wiki/Shoelace_formula>
+    pub fn twice_signed_area(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| a.x as i128 * b.y as i128 - b.x as i128 * a.y as i128)
+            .sum()
+    }
+
+    /// Clockwise positive, see [`Polygon::twice_signed_area`], rounded towards zero for the
+    /// odd half that only non-rectilinear polygons can have.
+    pub fn signed_area(&self) -> i128 {
+        self.twice_signed_area() / 2
+    }
+
+    /// The length of the edges walked along the grid, the perimeter of a rectilinear polygon.
+    pub fn perimeter(&self) -> i128 {
+        self.edges().map(|(a, b)| a.manhattan(b) as i128).sum()
+    }
+
+    /// How many grid points the edges pass through, the same as the perimeter for a
+    /// rectilinear polygon.
+    pub fn boundary_points(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| gcd(a.x.abs_diff(b.x), a.y.abs_diff(b.y)) as i128)
+            .sum()
+    }
+
+    /// How many grid points are strictly inside, with Pick's theorem `A = i + b/2 - 1`.
+    ///
+    /// Only holds for a simple polygon, see [`Polygon::is_self_intersecting`].
+    /// <https://en.wikipedia.org/wiki/Pick%27s_theorem>
+    pub fn interior_points(&self) -> i128 {
+        (self.twice_signed_area().abs() - self.boundary_points() + 2) / 2
+    }
+
+    /// Whether `point` is on one of the edges.
+    pub fn on_boundary(&self, point: Vec2<i64>) -> bool {
+        self.edges().any(|(a, b)| on_segment(point, a, b))
+    }
+
+    /// Whether `point` is strictly inside, points on the boundary aren't.
+    pub fn contains(&self, point: Vec2<i64>) -> bool {
+        if self.on_boundary(point) {
+            return false;
+        }
+        // count the edges crossing a ray going right from `point`
+        let crossings = self.edges().filter(|&(a, b)| {
+            if (a.y > point.y) == (b.y > point.y) {
+                return false;
+            }
+            // which side of the edge the point is on, flipped for edges going up
+            let side = orientation(a, b, point);
+            if b.y > a.y {
+                side > 0
+            } else {
+                side < 0
+            }
+        });
+        crossings.count() % 2 == 1
+    }
+
+    /// Whether any two edges touch other than neighbours sharing their corner, including an
+    /// edge doubling back over the one before it.
+    pub fn is_self_intersecting(&self) -> bool {
+        let edges: Vec<_> = self.edges().collect();
+        let n = edges.len();
+        for i in 0..n {
+            for j in i + 1..n {
+                let (a, b) = edges[i];
+                let (c, d) = edges[j];
+                let neighbours = j == i + 1 || (i == 0 && j == n - 1);
+                if neighbours {
+                    // they meet at a corner, which only counts if one folds back onto the other
+                    let (corner, p, q) = if j == i + 1 { (b, a, d) } else { (a, b, c) };
+                    if n > 2 && orientation(corner, p, q) == 0 && dot(p - corner, q - corner) > 0 {
+                        return true;
+                    }
+                } else if segments_intersect(a, b, c, d) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn dot(a: Vec2<i64>, b: Vec2<i64>) -> i128 {
+    a.x as i128 * b.x as i128 + a.y as i128 * b.y as i128
+}
+
+/// The sign of the cross product of `a -> b` and `a -> c`: 0 when the three are collinear.
+fn orientation(a: Vec2<i64>, b: Vec2<i64>, c: Vec2<i64>) -> i128 {
+    let ab = Vec2::new((b.x - a.x) as i128, (b.y - a.y) as i128);
+    let ac = Vec2::new((c.x - a.x) as i128, (c.y - a.y) as i128);
+    ab.cross(ac).signum()
+}
+
+fn on_segment(p: Vec2<i64>, a: Vec2<i64>, b: Vec2<i64>) -> bool {
+    orientation(a, b, p) == 0
+        && (a.x.min(b.x)..=a.x.max(b.x)).contains(&p.x)
+        && (a.y.min(b.y)..=a.y.max(b.y)).contains(&p.y)
+}
+
+fn segments_intersect(a: Vec2<i64>, b: Vec2<i64>, c: Vec2<i64>, d: Vec2<i64>) -> bool {
+    let (o1, o2) = (orientation(a, b, c), orientation(a, b, d));
+    let (o3, o4) = (orientation(c, d, a), orientation(c, d, b));
+    (o1 * o2 < 0 && o3 * o4 < 0)
+        || on_segment(c, a, b)
+        || on_segment(d, a, b)
+        || on_segment(a, c, d)
+        || on_segment(b, c, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 3x2 rectangle, clockwise on screen
+    fn rectangle() -> Polygon {
+        Polygon::from_moves(
+            Vec2::new(0, 0),
+            [
+                (Dir4::Right, 3),
+                (Dir4::Down, 2),
+                (Dir4::Left, 3),
+                (Dir4::Up, 2),
+            ],
+        )
+    }
+
+    #[test]
+    fn measures() {
+        let p = rectangle();
+        assert_eq!(p.vertices().len(), 4);
+        assert_eq!(p.signed_area(), 6);
+        assert_eq!((p.perimeter(), p.boundary_points()), (10, 10));
+        assert_eq!(p.interior_points(), 2);
+
+        let reversed = Polygon::new(p.vertices().iter().rev().copied().collect());
+        assert_eq!(reversed.signed_area(), -6);
+        assert_eq!(reversed.interior_points(), 2);
+
+        // a triangle with a diagonal edge
+        let t = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(0, 4)]);
+        assert_eq!(t.twice_signed_area(), 16);
+        assert_eq!(t.boundary_points(), 12);
+        assert_eq!(t.interior_points(), 3);
+    }
+
+    #[test]
+    fn day18_2023_example() {
+        let moves = [
+            (Dir4::Right, 6),
+            (Dir4::Down, 5),
+            (Dir4::Left, 2),
+            (Dir4::Down, 2),
+            (Dir4::Right, 2),
+            (Dir4::Down, 2),
+            (Dir4::Left, 5),
+            (Dir4::Up, 2),
+            (Dir4::Left, 1),
+            (Dir4::Up, 2),
+            (Dir4::Right, 2),
+            (Dir4::Up, 3),
+            (Dir4::Left, 2),
+            (Dir4::Up, 2),
+        ];
+        let p = Polygon::from_moves(Vec2::new(0, 0), moves);
+        assert_eq!(p.interior_points() + p.boundary_points(), 62);
+        assert!(!p.is_self_intersecting());
+    }
+
+    #[test]
+    fn large() {
+        // part 2 sized, the area doesn't fit in an i64
+        let side = 4_000_000_000;
+        let p = Polygon::from_moves(
+            Vec2::new(0, 0),
+            [(Dir4::Right, side), (Dir4::Down, side), (Dir4::Left, side)],
+        );
+        assert_eq!(p.twice_signed_area(), 2 * (side as i128).pow(2));
+        assert_eq!(p.perimeter(), 4 * side as i128);
+    }
+
+    #[test]
+    fn contains() {
+        let p = rectangle();
+        assert!(p.contains(Vec2::new(1, 1)));
+        assert!(p.contains(Vec2::new(2, 1)));
+        assert!(!p.contains(Vec2::new(3, 1)));
+        assert!(p.on_boundary(Vec2::new(3, 1)));
+        assert!(!p.contains(Vec2::new(4, 1)));
+        assert!(!p.contains(Vec2::new(-1, 0)));
+
+        // a U shape, the gap isn't inside
+        let u = Polygon::from_moves(
+            Vec2::new(0, 0),
+            [
+                (Dir4::Right, 1),
+                (Dir4::Down, 2),
+                (Dir4::Right, 2),
+                (Dir4::Up, 2),
+                (Dir4::Right, 1),
+                (Dir4::Down, 4),
+                (Dir4::Left, 4),
+            ],
+        );
+        assert!(!u.contains(Vec2::new(2, 1)));
+        assert!(u.contains(Vec2::new(2, 3)));
+        assert!(u.contains(Vec2::new(3, 3)));
+    }
+
+    #[test]
+    fn self_intersection() {
+        assert!(!rectangle().is_self_intersecting());
+
+        // a figure of eight crossing itself in the middle
+        let eight = Polygon::new(vec![
+            Vec2::new(0, 0),
+            Vec2::new(2, 0),
+            Vec2::new(2, 2),
+            Vec2::new(4, 2),
+            Vec2::new(4, 1),
+            Vec2::new(0, 1),
+        ]);
+        assert!(eight.is_self_intersecting());
+
+        // doubling back along the previous edge
+        let folded = Polygon::from_moves(
+            Vec2::new(0, 0),
+            [(Dir4::Right, 3), (Dir4::Left, 1), (Dir4::Down, 1)],
+        );
+        assert!(folded.is_self_intersecting());
+    }
+}
@@ -0,0 +1,201 @@
+use crate::Vec2;
+
+/// An axis-aligned rectangle of grid cells, both corners included like puzzle inputs write
+/// them, so it's never empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    min: Vec2<i64>,
+    max: Vec2<i64>,
+}
+
+impl Rect {
+    /// Any two opposite corners, in either order.
+    pub fn from_corners(a: Vec2<i64>, b: Vec2<i64>) -> Self {
+        Self {
+            min: Vec2::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Vec2::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    /// `size` cells across and down from `min`, `None` unless both are at least 1.
+    pub fn with_size(min: Vec2<i64>, size: Vec2<i64>) -> Option<Self> {
+        (size.x > 0 && size.y > 0).then(|| Self {
+            min,
+            max: min + size - Vec2::new(1, 1),
+        })
+    }
+
+    /// The top left corner.
+    pub fn min(&self) -> Vec2<i64> {
+        self.min
+    }
+
+    /// The bottom right corner, inside the rect.
+    pub fn max(&self) -> Vec2<i64> {
+        self.max
+    }
+
+    /// Cells across and down.
+    pub fn size(&self) -> Vec2<i64> {
+        self.max - self.min + Vec2::new(1, 1)
+    }
+
+    pub fn area(&self) -> i64 {
+        let size = self.size();
+        size.x * size.y
+    }
+
+    /// Around the outside of the cells.
+    pub fn perimeter(&self) -> i64 {
+        let size = self.size();
+        2 * (size.x + size.y)
+    }
+
+    pub fn contains(&self, point: Vec2<i64>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// Whether they share at least one cell.
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    /// The cells in both, if there are any.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        self.intersects(other).then(|| Rect {
+            min: Vec2::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y)),
+            max: Vec2::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y)),
+        })
+    }
+
+    /// Every cell, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = Vec2<i64>> {
+        let Rect { min, max } = *self;
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Vec2::new(x, y)))
+    }
+
+    pub fn translate(&self, offset: Vec2<i64>) -> Rect {
+        Rect {
+            min: self.min + offset,
+            max: self.max + offset,
+        }
+    }
+
+    /// The cells not in `other`, as up to 4 rects that don't overlap.
+    pub fn subtract(&self, other: &Rect) -> Vec<Rect> {
+        let Some(cut) = self.intersection(other) else {
+            return vec![*self];
+        };
+        let mut pieces = Vec::new();
+        // full width above and below the cut, then either side of it
+        if self.min.y < cut.min.y {
+            pieces.push(Rect::from_corners(
+                self.min,
+                Vec2::new(self.max.x, cut.min.y - 1),
+            ));
+        }
+        if cut.max.y < self.max.y {
+            pieces.push(Rect::from_corners(
+                Vec2::new(self.min.x, cut.max.y + 1),
+                self.max,
+            ));
+        }
+        if self.min.x < cut.min.x {
+            pieces.push(Rect::from_corners(
+                Vec2::new(self.min.x, cut.min.y),
+                Vec2::new(cut.min.x - 1, cut.max.y),
+            ));
+        }
+        if cut.max.x < self.max.x {
+            pieces.push(Rect::from_corners(
+                Vec2::new(cut.max.x + 1, cut.min.y),
+                Vec2::new(self.max.x, cut.max.y),
+            ));
+        }
+        pieces
+    }
+
+    /// How many cells are in at least one of the rects, overlaps counted once.
+    pub fn union_area(rects: impl IntoIterator<Item = Rect>) -> i64 {
+        let mut disjoint: Vec<Rect> = Vec::new();
+        for rect in rects {
+            disjoint = disjoint.iter().flat_map(|d| d.subtract(&rect)).collect();
+            disjoint.push(rect);
+        }
+        disjoint.iter().map(Rect::area).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rect_measures() {
+        let r = Rect::from_corners(Vec2::new(3, 4), Vec2::new(1, 1));
+        assert_eq!((r.min(), r.max()), (Vec2::new(1, 1), Vec2::new(3, 4)));
+        assert_eq!(Rect::with_size(Vec2::new(1, 1), Vec2::new(3, 4)), Some(r));
+        assert_eq!(Rect::with_size(Vec2::new(1, 1), Vec2::new(0, 4)), None);
+        assert_eq!((r.area(), r.perimeter()), (12, 14));
+        assert!(r.contains(Vec2::new(3, 1)));
+        assert!(!r.contains(Vec2::new(0, 1)));
+
+        let moved = r.translate(Vec2::new(-1, 2));
+        assert_eq!(moved.min(), Vec2::new(0, 3));
+        assert_eq!(moved.size(), r.size());
+
+        let single = Rect::from_corners(Vec2::new(5, 5), Vec2::new(5, 5));
+        assert_eq!(single.area(), 1);
+        assert_eq!(single.cells().collect::<Vec<_>>(), [Vec2::new(5, 5)]);
+
+        let cells: Vec<_> = Rect::from_corners(Vec2::new(0, 0), Vec2::new(1, 1))
+            .cells()
+            .collect();
+        let expected = [(0, 0), (1, 0), (0, 1), (1, 1)].map(|(x, y)| Vec2::new(x, y));
+        assert_eq!(cells, expected);
+    }
+
+    #[test]
+    fn rect_intersection() {
+        let a = Rect::from_corners(Vec2::new(0, 0), Vec2::new(4, 4));
+        let b = Rect::from_corners(Vec2::new(3, 2), Vec2::new(6, 8));
+        let c = Rect::from_corners(Vec2::new(5, 0), Vec2::new(6, 2));
+        assert_eq!(
+            a.intersection(&b),
+            Some(Rect::from_corners(Vec2::new(3, 2), Vec2::new(4, 4)))
+        );
+        assert!(a.intersects(&b) && b.intersects(&c));
+        assert!(!a.intersects(&c));
+        assert_eq!(a.intersection(&c), None);
+        // touching at an edge still shares cells
+        assert!(a.intersects(&a.translate(Vec2::new(4, 0))));
+    }
+
+    #[test]
+    fn rect_subtract() {
+        let a = Rect::from_corners(Vec2::new(0, 0), Vec2::new(4, 4));
+        let hole = Rect::from_corners(Vec2::new(1, 1), Vec2::new(2, 3));
+        let pieces = a.subtract(&hole);
+        assert_eq!(pieces.len(), 4);
+        assert_eq!(pieces.iter().map(Rect::area).sum::<i64>(), 25 - 6);
+        for cell in a.cells() {
+            let covering = pieces.iter().filter(|p| p.contains(cell)).count();
+            assert_eq!(covering, usize::from(!hole.contains(cell)));
+        }
+
+        assert!(a.subtract(&a).is_empty());
+        assert_eq!(hole.subtract(&a.translate(Vec2::new(10, 0))), [hole]);
+    }
+
+    #[test]
+    fn rect_union_area() {
+        let a = Rect::from_corners(Vec2::new(0, 0), Vec2::new(999, 999));
+        let b = Rect::from_corners(Vec2::new(499, 499), Vec2::new(1500, 500));
+        assert_eq!(Rect::union_area([a]), 1_000_000);
+        assert_eq!(Rect::union_area([a, b, a]), 1_000_000 + 2 * 501);
+        assert_eq!(Rect::union_area([]), 0);
+    }
+}
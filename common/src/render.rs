@@ -0,0 +1,221 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use crate::{Matrix, Vec2};
+
+/// How highlighted cells are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Highlight {
+    /// Drawn as this char instead of the cell.
+    Char(char),
+    /// The cell drawn in this colour, on a terminal that understands ANSI escapes.
+    Colour(Colour),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Colour {
+    /// The ANSI foreground colour code.
+    fn code(self) -> u8 {
+        match self {
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+        }
+    }
+}
+
+/// A drawing of a matrix for debugging, see [`Matrix::render`], draws itself with [`Display`].
+///
+/// By default rows look like `[a b c]`, the same as printing the matrix, highlighted cells
+/// and paths are drawn on top.
+pub struct Render<'a, T> {
+    matrix: &'a Matrix<T>,
+    cell: Box<dyn Fn(&T) -> String + 'a>,
+    compact: bool,
+    highlights: Vec<(HashSet<Vec2<usize>>, Highlight)>,
+    arrows: HashMap<Vec2<usize>, char>,
+}
+
+impl<'a, T> Render<'a, T> {
+    /// Rows without brackets or spaces between cells, best with one char per cell.
+    pub fn compact(mut self) -> Self {
+        self.compact = true;
+        self
+    }
+
+    /// Draws `positions` with `highlight`, later highlights win where they overlap.
+    ///
+    /// Positions have `x` the column and `y` the row, those outside the matrix are ignored.
+    pub fn highlight(
+        mut self,
+        positions: impl IntoIterator<Item = Vec2<usize>>,
+        highlight: Highlight,
+    ) -> Self {
+        self.highlights
+            .push((positions.into_iter().collect(), highlight));
+        self
+    }
+
+    /// Draws an arrow on each cell of `path` pointing to the next one, the last cell is left
+    /// as it is. Arrows are drawn over the cells and char highlights, colours still apply.
+    pub fn path(mut self, path: &[Vec2<usize>]) -> Self {
+        for step in path.windows(2) {
+            let (Some(from), Some(to)) = (step[0].to_isize(), step[1].to_isize()) else {
+                continue;
+            };
+            if let Some(arrow) = arrow((to - from).signum()) {
+                self.arrows.insert(step[0], arrow);
+            }
+        }
+        self
+    }
+
+    /// The cell at `pos` as it is drawn.
+    fn draw(&self, pos: Vec2<usize>, element: &T) -> String {
+        let highlight = |pick: fn(&Highlight) -> bool| {
+            self.highlights
+                .iter()
+                .rev()
+                .find(|(positions, highlight)| pick(highlight) && positions.contains(&pos))
+                .map(|(_, highlight)| *highlight)
+        };
+
+        let cell = match (
+            self.arrows.get(&pos),
+            highlight(|h| matches!(h, Highlight::Char(_))),
+        ) {
+            (Some(arrow), _) => arrow.to_string(),
+            (None, Some(Highlight::Char(char))) => char.to_string(),
+            _ => (self.cell)(element),
+        };
+        match highlight(|h| matches!(h, Highlight::Colour(_))) {
+            Some(Highlight::Colour(colour)) => format!("\x1b[{}m{cell}\x1b[0m", colour.code()),
+            _ => cell,
+        }
+    }
+}
+
+/// The arrow for a unit step, diagonals included.
+fn arrow(dir: Vec2<isize>) -> Option<char> {
+    let arrow = match (dir.x, dir.y) {
+        (1, 0) => '>',
+        (-1, 0) => '<',
+        (0, -1) => '^',
+        (0, 1) => 'v',
+        (1, -1) => '↗',
+        (1, 1) => '↘',
+        (-1, 1) => '↙',
+        (-1, -1) => '↖',
+        _ => return None,
+    };
+    Some(arrow)
+}
+
+impl<T> Display for Render<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.matrix.rows().enumerate() {
+            if !self.compact {
+                write!(f, "[")?;
+            }
+            for (x, element) in row.iter().enumerate() {
+                if x > 0 && !self.compact {
+                    write!(f, " ")?;
+                }
+                write!(f, "{}", self.draw(Vec2::new(x, y), element))?;
+            }
+            if !self.compact {
+                write!(f, "]")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Draws each cell with its [`Display`].
+    pub fn render(&self) -> Render<'_, T>
+    where
+        T: Display,
+    {
+        self.render_with(T::to_string)
+    }
+
+    /// Draws each cell as `cell` formats it.
+    pub fn render_with<'a>(&'a self, cell: impl Fn(&T) -> String + 'a) -> Render<'a, T> {
+        Render {
+            matrix: self,
+            cell: Box::new(cell),
+            compact: false,
+            highlights: Vec::new(),
+            arrows: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix() -> Matrix<char> {
+        Matrix::parse_grid("#..\n.#.\n", |c| c).unwrap()
+    }
+
+    #[test]
+    fn formats() {
+        let m = matrix();
+        assert_eq!(m.render().to_string(), "[# . .]\n[. # .]\n");
+        assert_eq!(m.render().to_string(), m.to_string());
+        assert_eq!(m.render().compact().to_string(), "#..\n.#.\n");
+        assert_eq!(
+            m.render_with(|&c| (if c == '#' { "##" } else { "  " }).to_owned())
+                .compact()
+                .to_string(),
+            "##    \n  ##  \n"
+        );
+    }
+
+    #[test]
+    fn highlights() {
+        let m = matrix();
+        let drawn = m
+            .render()
+            .compact()
+            .highlight([Vec2::new(1, 0), Vec2::new(9, 9)], Highlight::Char('O'))
+            .highlight([Vec2::new(1, 0)], Highlight::Char('X'))
+            .highlight([Vec2::new(2, 1)], Highlight::Colour(Colour::Red));
+        assert_eq!(drawn.to_string(), "#X.\n.#\x1b[31m.\x1b[0m\n");
+    }
+
+    #[test]
+    fn paths() {
+        let m = matrix();
+        let path = [
+            Vec2::new(0, 1),
+            Vec2::new(0, 0),
+            Vec2::new(1, 1),
+            Vec2::new(2, 1),
+            Vec2::new(2, 0),
+        ];
+        let drawn = m
+            .render()
+            .compact()
+            .highlight(path, Highlight::Char('*'))
+            .path(&path);
+        assert_eq!(drawn.to_string(), "↘.*\n^>^\n");
+    }
+}
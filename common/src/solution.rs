@@ -0,0 +1,96 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use log::info;
+
+use crate::{input, logging, AocError};
+
+/// A single day's puzzle.
+///
+/// The input is parsed once and shared by both parts, so runners, benches and tests
+/// can call into a day without going through its binaries. A bad input is an
+/// [`AocError`] rather than a panic.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+    fn part1(input: &Self::Input) -> Result<Self::Output1, AocError>;
+    fn part2(input: &Self::Input) -> Result<Self::Output2, AocError>;
+
+    /// Example fixtures can pass [`Params`], e.g. day 11's expansion factor, days that
+    /// take any override these.
+    fn part1_with(input: &Self::Input, _params: &Params) -> Result<Self::Output1, AocError> {
+        Self::part1(input)
+    }
+
+    fn part2_with(input: &Self::Input, _params: &Params) -> Result<Self::Output2, AocError> {
+        Self::part2(input)
+    }
+}
+
+/// Extra knobs for a part that the real puzzle input doesn't need.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, key: &str, value: impl ToString) -> Self {
+        self.insert(key, value);
+        self
+    }
+
+    pub fn insert(&mut self, key: &str, value: impl ToString) {
+        self.0.insert(key.to_owned(), value.to_string());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Panics when the value is there but doesn't parse, a typo in a fixture shouldn't
+    /// silently fall back to the default.
+    pub fn get<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.0.get(key).map(|value| {
+            value
+                .parse()
+                .unwrap_or_else(|_| panic!("could not parse param {key} from '{value}'"))
+        })
+    }
+}
+
+/// The `main` shared by every `src/bin/partN.rs`, prints and returns the formatted answer.
+///
+/// Takes an optional input path as the first argument (`-` for stdin), otherwise reads
+/// the day from `AOC_INPUT_DIR` or falls back to the `default` path. `-v`, `-vv`, `-vvv`
+/// show more of what the day logs, see [`logging::verbosity`].
+pub fn run<S: Solution>(part: u8, default: &str) -> String {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    logging::init(logging::verbosity(&mut args));
+
+    let input = input::load(args.first().map(String::as_str), S::YEAR, S::DAY, default)
+        .unwrap_or_else(|err| exit(&err));
+
+    info!("{} day {} part {part}", S::YEAR, S::DAY);
+    let now = std::time::Instant::now();
+    let output = S::parse(&input).and_then(|input| match part {
+        1 => S::part1(&input).map(|output| output.to_string()),
+        2 => S::part2(&input).map(|output| output.to_string()),
+        _ => panic!("there is no part {part}"),
+    });
+    let output = output.unwrap_or_else(|err| exit(&err));
+    info!("finished in {:?}", now.elapsed());
+    println!("{output}");
+    output
+}
+
+fn exit(err: &dyn Display) -> ! {
+    eprintln!("{err}");
+    std::process::exit(1);
+}
@@ -0,0 +1,209 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{
+    matrix::{ADJACENT, ORTHOGONAL},
+    Matrix, Vec2,
+};
+
+/// A grid without edges, only the occupied cells are stored.
+///
+/// Positions can be anywhere, negative included, [`SparseGrid::bounds`] tracks the smallest
+/// rectangle holding them all.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Vec2<isize>, T>,
+    /// The top left and bottom right corners, inclusive, `None` while empty.
+    bounds: Option<(Vec2<isize>, Vec2<isize>)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns what was at `pos` before.
+    pub fn insert(&mut self, pos: Vec2<isize>, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (
+                Vec2::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Vec2::new(max.x.max(pos.x), max.y.max(pos.y)),
+            ),
+            None => (pos, pos),
+        });
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: Vec2<isize>) -> Option<T> {
+        let value = self.cells.remove(&pos)?;
+        let (min, max) = self.bounds.expect("an occupied grid has bounds");
+        // only a cell on the edge can shrink the bounds
+        if pos.x == min.x || pos.x == max.x || pos.y == min.y || pos.y == max.y {
+            self.bounds = self.cells.keys().fold(None, |bounds, &pos| {
+                Some(match bounds {
+                    Some((min, max)) => (
+                        Vec2::new(pos.x.min(min.x), pos.y.min(min.y)),
+                        Vec2::new(pos.x.max(max.x), pos.y.max(max.y)),
+                    ),
+                    None => (pos, pos),
+                })
+            });
+        }
+        Some(value)
+    }
+
+    pub fn get(&self, pos: Vec2<isize>) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Vec2<isize>) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn contains(&self, pos: Vec2<isize>) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// The occupied cells, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2<isize>, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    /// The top left and bottom right occupied corners, inclusive, `None` if nothing is.
+    pub fn bounds(&self) -> Option<(Vec2<isize>, Vec2<isize>)> {
+        self.bounds
+    }
+
+    /// The occupied cells among the 4 up, right, down and left of `pos`, in that order.
+    pub fn neighbours4(&self, pos: Vec2<isize>) -> impl Iterator<Item = (Vec2<isize>, &T)> {
+        ORTHOGONAL.into_iter().filter_map(move |offset| {
+            let pos = pos + offset;
+            Some((pos, self.get(pos)?))
+        })
+    }
+
+    /// The occupied cells among the 8 around `pos`, diagonals included, in reading order.
+    pub fn neighbours8(&self, pos: Vec2<isize>) -> impl Iterator<Item = (Vec2<isize>, &T)> {
+        ADJACENT.into_iter().filter_map(move |offset| {
+            let pos = pos + offset;
+            Some((pos, self.get(pos)?))
+        })
+    }
+
+    /// The bounding box as a matrix with `fill` where nothing is, and the position of its top
+    /// left corner: the cell at `pos` ends up at `pos - offset`.
+    pub fn to_matrix(&self, fill: T) -> (Matrix<T>, Vec2<isize>)
+    where
+        T: Clone,
+    {
+        let Some((min, max)) = self.bounds else {
+            return (Matrix::new(0, 0, Vec::new()), Vec2::ZERO);
+        };
+        let nrows = (max.y - min.y + 1) as usize;
+        let ncols = (max.x - min.x + 1) as usize;
+        let mut matrix = Matrix::new(nrows, ncols, vec![fill; nrows * ncols]);
+        for (&pos, value) in &self.cells {
+            let pos = pos - min;
+            matrix[(pos.y as usize, pos.x as usize)] = value.clone();
+        }
+        (matrix, min)
+    }
+}
+
+impl<T> FromIterator<(Vec2<isize>, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Vec2<isize>, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (pos, value) in iter {
+            grid.insert(pos, value);
+        }
+        grid
+    }
+}
+
+/// The bounding box one row per line with a `.` where nothing is, compact like
+/// [`crate::render::Render::compact`], so best with one char per cell.
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return Ok(());
+        };
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                match self.get(Vec2::new(x, y)) {
+                    Some(value) => write!(f, "{value}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert(Vec2::new(2, -1), 'a');
+        grid.insert(Vec2::new(-3, 4), 'b');
+        grid.insert(Vec2::new(0, 0), 'c');
+        assert_eq!(grid.bounds(), Some((Vec2::new(-3, -1), Vec2::new(2, 4))));
+
+        // an inside cell leaves the bounds alone, an edge one shrinks them
+        assert_eq!(grid.remove(Vec2::new(0, 0)), Some('c'));
+        assert_eq!(grid.remove(Vec2::new(0, 0)), None);
+        assert_eq!(grid.remove(Vec2::new(-3, 4)), Some('b'));
+        assert_eq!(grid.bounds(), Some((Vec2::new(2, -1), Vec2::new(2, -1))));
+        grid.remove(Vec2::new(2, -1));
+        assert_eq!(grid.bounds(), None);
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid: SparseGrid<_> = [(Vec2::new(0, -1), 1), (Vec2::new(1, 1), 2), (Vec2::ZERO, 3)]
+            .into_iter()
+            .collect();
+        let n4: Vec<_> = grid.neighbours4(Vec2::ZERO).collect();
+        assert_eq!(n4, [(Vec2::new(0, -1), &1)]);
+        let n8: Vec<_> = grid.neighbours8(Vec2::ZERO).map(|(_, v)| *v).collect();
+        assert_eq!(n8, [1, 2]);
+    }
+
+    #[test]
+    fn to_matrix_and_display() {
+        let grid: SparseGrid<_> = [(Vec2::new(-1, -1), '#'), (Vec2::new(1, 0), '#')]
+            .into_iter()
+            .collect();
+        let (matrix, offset) = grid.to_matrix(' ');
+        assert_eq!(offset, Vec2::new(-1, -1));
+        assert_eq!(
+            matrix,
+            Matrix::new(2, 3, vec!['#', ' ', ' ', ' ', ' ', '#'])
+        );
+        assert_eq!(grid.to_string(), "#..\n..#\n");
+
+        let (empty, _) = SparseGrid::<char>::new().to_matrix(' ');
+        assert_eq!((empty.nrows(), empty.ncols()), (0, 0));
+    }
+}
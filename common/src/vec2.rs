@@ -92,6 +92,64 @@ where
     }
 }
 
+/// Scales both components.
+impl<T> std::ops::Mul<T> for Vec2<T>
+where
+    T: std::ops::Mul<Output = T> + Copy,
+{
+    type Output = Vec2<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+/// Divides both components, rounding like `T` does.
+impl<T> std::ops::Div<T> for Vec2<T>
+where
+    T: std::ops::Div<Output = T> + Copy,
+{
+    type Output = Vec2<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
+
+impl<T: std::ops::AddAssign> std::ops::AddAssign for Vec2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: std::ops::SubAssign> std::ops::SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: std::ops::MulAssign> std::ops::MulAssign for Vec2<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+    }
+}
+
+impl<T: std::ops::MulAssign + Copy> std::ops::MulAssign<T> for Vec2<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
 impl<T> std::ops::Neg for Vec2<T>
 where
     T: std::ops::Neg<Output = T>,
@@ -106,6 +164,86 @@ where
     }
 }
 
+impl<T> Vec2<T>
+where
+    T: std::ops::Mul<Output = T> + std::ops::Add<Output = T> + std::ops::Sub<Output = T>,
+    T: Copy,
+{
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The z of the 3D cross product, positive when `other` is clockwise from `self` with
+    /// y pointing down.
+    pub fn cross(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+}
+
+/// Distances come back unsigned, each axis's difference fits even between opposite corners.
+macro_rules! distances {
+    ($($t:ty => $unsigned:ty),*) => {$(
+        impl Vec2<$t> {
+            /// Steps between the two with only orthogonal moves.
+            ///
+            /// Adding up the axes overflows once the points are more than the unsigned range apart,
+            /// `i64::MIN` to `i64::MAX` on both axes say, which panics in debug builds.
+            pub fn manhattan(self, other: Self) -> $unsigned {
+                self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+            }
+
+            /// Steps between the two with diagonal moves too.
+            pub fn chebyshev(self, other: Self) -> $unsigned {
+                self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+            }
+        }
+    )*};
+}
+
+distances!(
+    i32 => u32, i64 => u64, i128 => u128, isize => usize,
+    u32 => u32, u64 => u64, u128 => u128, usize => usize
+);
+
+macro_rules! signs {
+    ($($t:ty),*) => {$(
+        impl Vec2<$t> {
+            pub fn abs(self) -> Self {
+                Self::new(self.x.abs(), self.y.abs())
+            }
+
+            /// Each component as -1, 0 or 1, a diagonal or orthogonal unit step towards it.
+            pub fn signum(self) -> Self {
+                Self::new(self.x.signum(), self.y.signum())
+            }
+        }
+    )*};
+}
+
+signs!(i32, i64, i128, isize);
+
+impl Vec2<usize> {
+    /// `None` if a component doesn't fit in an `isize`.
+    pub fn to_isize(self) -> Option<Vec2<isize>> {
+        Some(Vec2::new(self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+
+    /// `None` if the result would be negative or overflow.
+    pub fn checked_add_signed(self, offset: Vec2<isize>) -> Option<Self> {
+        Some(Vec2::new(
+            self.x.checked_add_signed(offset.x)?,
+            self.y.checked_add_signed(offset.y)?,
+        ))
+    }
+}
+
+impl Vec2<isize> {
+    /// `None` if a component is negative.
+    pub fn to_usize(self) -> Option<Vec2<usize>> {
+        Some(Vec2::new(self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
 impl<T: Display> Display for Vec2<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "({},{})", self.x, self.y)
@@ -123,6 +261,51 @@ mod tests {
         assert_eq!(v.y, 2);
     }
 
+    #[test]
+    fn vec2_ops() {
+        let mut v = Vec2::new(3, -4);
+        assert_eq!(v * 2, Vec2::new(6, -8));
+        assert_eq!(v / 2, Vec2::new(1, -2));
+        v += Vec2::new(1, 1);
+        v -= Vec2::new(2, 0);
+        assert_eq!(v, Vec2::new(2, -3));
+        v *= 3;
+        v *= Vec2::new(1, -1);
+        assert_eq!(v, Vec2::new(6, 9));
+
+        assert_eq!(Vec2::new(1, 2).dot(Vec2::new(3, 4)), 11);
+        assert_eq!(Vec2::RIGHT.cross(Vec2::DOWN), 1);
+        assert_eq!(Vec2::DOWN.cross(Vec2::RIGHT), -1);
+    }
+
+    #[test]
+    fn vec2_distances() {
+        let a = Vec2::new(-100isize, -100);
+        let b = Vec2::new(100, 50);
+        assert_eq!(a.manhattan(b), 350);
+        assert_eq!(a.chebyshev(b), 200);
+        assert_eq!(Vec2::new(2usize, 9).manhattan(Vec2::new(4, 4)), 7);
+        assert_eq!(
+            Vec2::new(i64::MIN, 0).manhattan(Vec2::new(i64::MAX, 0)),
+            u64::MAX
+        );
+
+        assert_eq!(Vec2::new(-3isize, 0).abs(), Vec2::new(3, 0));
+        assert_eq!(Vec2::new(-3i32, 7).signum(), Vec2::new(-1, 1));
+    }
+
+    #[test]
+    fn vec2_conversions() {
+        assert_eq!(Vec2::new(1isize, 2).to_usize(), Some(Vec2::new(1usize, 2)));
+        assert_eq!(Vec2::new(1isize, -2).to_usize(), None);
+        assert_eq!(Vec2::new(1usize, 2).to_isize(), Some(Vec2::new(1isize, 2)));
+        assert_eq!(Vec2::new(usize::MAX, 0).to_isize(), None);
+
+        let v = Vec2::new(1usize, 0);
+        assert_eq!(v.checked_add_signed(Vec2::LEFT), Some(Vec2::new(0, 0)));
+        assert_eq!(v.checked_add_signed(Vec2::UP), None);
+    }
+
     #[test]
     fn vec2_left_rot() {
         let mut v = Vec2::new(1, 0);
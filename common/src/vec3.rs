@@ -0,0 +1,292 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Vector 3
+///
+/// Like [`crate::Vec2`] with a `z`, orders by `x`, then `y`, then `z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vec3<T>
+where
+    T: Clone + Copy,
+{
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> std::ops::Add for Vec3<T>
+where
+    T: std::ops::Add<Output = T>,
+{
+    type Output = Vec3<T>;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl<T> std::ops::Sub for Vec3<T>
+where
+    T: std::ops::Sub<Output = T>,
+{
+    type Output = Vec3<T>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl<T> std::ops::Mul for Vec3<T>
+where
+    T: std::ops::Mul<Output = T>,
+{
+    type Output = Vec3<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x * rhs.x,
+            y: self.y * rhs.y,
+            z: self.z * rhs.z,
+        }
+    }
+}
+
+/// Scales all components.
+impl<T> std::ops::Mul<T> for Vec3<T>
+where
+    T: std::ops::Mul<Output = T> + Copy,
+{
+    type Output = Vec3<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+/// Divides all components, rounding like `T` does.
+impl<T> std::ops::Div<T> for Vec3<T>
+where
+    T: std::ops::Div<Output = T> + Copy,
+{
+    type Output = Vec3<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x / rhs,
+            y: self.y / rhs,
+            z: self.z / rhs,
+        }
+    }
+}
+
+impl<T: std::ops::AddAssign> std::ops::AddAssign for Vec3<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl<T: std::ops::SubAssign> std::ops::SubAssign for Vec3<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl<T: std::ops::MulAssign + Copy> std::ops::MulAssign<T> for Vec3<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+impl<T> std::ops::Neg for Vec3<T>
+where
+    T: std::ops::Neg<Output = T>,
+{
+    type Output = Vec3<T>;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl<T> Vec3<T>
+where
+    T: std::ops::Mul<Output = T> + std::ops::Add<Output = T> + std::ops::Sub<Output = T>,
+    T: Copy,
+{
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Perpendicular to both, zero when they are parallel.
+    ///
+    /// Products of `i64` puzzle coordinates can overflow, see `From<Vec3<i64>>` for `Vec3<i128>`.
+    pub fn cross(self, other: Self) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+}
+
+/// Unsigned like [`crate::Vec2`]'s distances.
+macro_rules! distances {
+    ($($t:ty => $unsigned:ty),*) => {$(
+        impl Vec3<$t> {
+            /// Steps between the two with only axis-aligned moves.
+            ///
+            /// Overflows when the three axes add up to more than the unsigned range, the same
+            /// way as the 2D one.
+            pub fn manhattan(self, other: Self) -> $unsigned {
+                self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+            }
+
+            /// Steps between the two with diagonal moves too.
+            pub fn chebyshev(self, other: Self) -> $unsigned {
+                (self.x.abs_diff(other.x))
+                    .max(self.y.abs_diff(other.y))
+                    .max(self.z.abs_diff(other.z))
+            }
+        }
+    )*};
+}
+
+distances!(
+    i32 => u32, i64 => u64, i128 => u128, isize => usize,
+    u32 => u32, u64 => u64, u128 => u128, usize => usize
+);
+
+macro_rules! signs {
+    ($($t:ty),*) => {$(
+        impl Vec3<$t> {
+            pub fn abs(self) -> Self {
+                Self::new(self.x.abs(), self.y.abs(), self.z.abs())
+            }
+
+            /// Each component as -1, 0 or 1.
+            pub fn signum(self) -> Self {
+                Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+            }
+        }
+    )*};
+}
+
+signs!(i32, i64, i128, isize);
+
+impl From<Vec3<i64>> for Vec3<i128> {
+    fn from(value: Vec3<i64>) -> Self {
+        Vec3::new(value.x.into(), value.y.into(), value.z.into())
+    }
+}
+
+/// `x,y,z`, the way puzzle inputs write them.
+impl<T: Display> Display for Vec3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// `x,y,z`, spaces around the numbers are fine.
+impl<T> FromStr for Vec3<T>
+where
+    T: FromStr + Copy,
+    T::Err: Display,
+{
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(',').map(|part| {
+            let part = part.trim();
+            part.parse::<T>().map_err(|err| format!("'{part}': {err}"))
+        });
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(x), Some(y), Some(z), None) => Ok(Vec3::new(x?, y?, z?)),
+            _ => Err(format!("expected x,y,z, got '{s}'")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec3_ops() {
+        let mut v = Vec3::new(1, -2, 3);
+        assert_eq!(v + Vec3::new(1, 1, 1), Vec3::new(2, -1, 4));
+        assert_eq!(v - Vec3::new(1, 1, 1), Vec3::new(0, -3, 2));
+        assert_eq!(v * 2, Vec3::new(2, -4, 6));
+        assert_eq!(v * Vec3::new(2, 0, -1), Vec3::new(2, 0, -3));
+        assert_eq!(v / 2, Vec3::new(0, -1, 1));
+        assert_eq!(-v, Vec3::new(-1, 2, -3));
+        v += Vec3::new(0, 2, 0);
+        v -= Vec3::new(1, 0, 0);
+        v *= 3;
+        assert_eq!(v, Vec3::new(0, 0, 9));
+
+        let x = Vec3::new(1, 0, 0);
+        let y = Vec3::new(0, 1, 0);
+        assert_eq!(x.cross(y), Vec3::new(0, 0, 1));
+        assert_eq!(x.dot(y), 0);
+        assert_eq!(Vec3::new(1, 2, 3).dot(Vec3::new(4, 5, 6)), 32);
+    }
+
+    #[test]
+    fn vec3_distances() {
+        let a = Vec3::new(1i64, -1, 5);
+        let b = Vec3::new(-2, 3, 5);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((a - b).abs(), Vec3::new(3, 4, 0));
+        assert_eq!((a - b).signum(), Vec3::new(1, -1, 0));
+    }
+
+    #[test]
+    fn vec3_i128() {
+        // hailstone sized, the cross product overflows i64
+        let a = Vec3::new(200_000_000_000_000i64, 300_000_000_000_000, 0);
+        let b = Vec3::new(-100_000_000_000_000i64, 400_000_000_000_000, 0);
+        let cross = Vec3::<i128>::from(a).cross(b.into());
+        assert_eq!(cross.z, 11 * 10i128.pow(28));
+    }
+
+    #[test]
+    fn vec3_text() {
+        let v: Vec3<i64> = "19, 13, 30".parse().unwrap();
+        assert_eq!(v, Vec3::new(19, 13, 30));
+        assert_eq!(v.to_string(), "19,13,30");
+        assert_eq!("-2,1,-2".parse(), Ok(Vec3::new(-2, 1, -2)));
+        assert!("1,2".parse::<Vec3<i64>>().is_err());
+        assert!("1,2,3,4".parse::<Vec3<i64>>().is_err());
+        assert!("1,x,3".parse::<Vec3<i64>>().is_err());
+
+        let mut bricks = [Vec3::new(1, 0, 2), Vec3::new(0, 5, 9), Vec3::new(0, 5, 1)];
+        bricks.sort();
+        assert_eq!(bricks[0], Vec3::new(0, 5, 1));
+    }
+}